@@ -0,0 +1,169 @@
+//! Batted ball geometry and quality; field positions, spray angles, pull/center/oppo, barrels and the sweet spot.
+
+use crate::{Handedness, game::{HitCoordinates, HitData, Play, PlayEvent, StatcastHitData}};
+
+/// Gameday coordinates of home plate, see [`HitCoordinates`].
+const HOME_PLATE: HitCoordinates = HitCoordinates { x: 125.42, y: 198.27 };
+
+/// Feet per Gameday coordinate unit.
+const FEET_PER_UNIT: f64 = 2.5;
+
+/// Spray angles within this many degrees of straightaway center field are [`HitDirection::Center`].
+const CENTER_HALF_ANGLE: f64 = 15.0;
+
+/// A point on the field relative to home plate, measured in feet.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct FieldPosition {
+	/// Positive towards the first base side.
+	pub x: f64,
+	/// Positive towards center field.
+	pub y: f64,
+}
+
+impl FieldPosition {
+	/// Distance from home plate, measured in feet.
+	#[must_use]
+	pub fn distance(&self) -> f64 {
+		self.x.hypot(self.y)
+	}
+
+	/// Horizontal angle from straightaway center field; negative is towards the left field line (-45), positive towards the right field line (45).
+	///
+	/// Measured in degrees.
+	#[must_use]
+	pub fn spray_angle(&self) -> f64 {
+		self.x.atan2(self.y).to_degrees()
+	}
+}
+
+/// Which third of the field a ball was hit to, relative to the batter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HitDirection {
+	/// Left field for a right-handed batter, right field for a left-handed batter.
+	Pull,
+	Center,
+	/// Right field for a right-handed batter, left field for a left-handed batter.
+	Opposite,
+}
+
+impl HitCoordinates {
+	/// Converts the Gameday coordinates into feet from home plate.
+	#[must_use]
+	pub fn field_position(&self) -> FieldPosition {
+		FieldPosition {
+			x: FEET_PER_UNIT * (self.x - HOME_PLATE.x),
+			y: FEET_PER_UNIT * (HOME_PLATE.y - self.y),
+		}
+	}
+
+	/// See [`FieldPosition::spray_angle`].
+	#[must_use]
+	pub fn spray_angle(&self) -> f64 {
+		self.field_position().spray_angle()
+	}
+
+	/// Pull, center, or opposite field for a batter hitting from `bat_side`.
+	///
+	/// `None` for [`Handedness::Switch`], use the side for the plate appearance, [`PlayMatchup::bat_side`](crate::game::PlayMatchup::bat_side).
+	#[must_use]
+	pub fn direction(&self, bat_side: Handedness) -> Option<HitDirection> {
+		let angle = self.spray_angle();
+		let pull_angle = match bat_side {
+			Handedness::Right => -angle,
+			Handedness::Left => angle,
+			Handedness::Switch => return None,
+		};
+		Some(if pull_angle > CENTER_HALF_ANGLE {
+			HitDirection::Pull
+		} else if pull_angle < -CENTER_HALF_ANGLE {
+			HitDirection::Opposite
+		} else {
+			HitDirection::Center
+		})
+	}
+}
+
+impl StatcastHitData {
+	/// Statcast's barrel; the exit velocity and launch angle combination that has led to at least a .500 batting average and 1.500 slugging percentage.
+	///
+	/// Requires 98 mph, at which the launch angle must be 26-30 degrees, widening with exit velocity to 8-50 degrees at 116 mph and no wider after.
+	#[must_use]
+	pub fn is_barrel(&self) -> bool {
+		let (exit_velocity, launch_angle) = (self.exit_velocity, self.launch_angle);
+		exit_velocity >= 98.0
+			&& (8.0..=50.0).contains(&launch_angle)
+			&& exit_velocity.mul_add(1.5, -launch_angle) >= 117.0
+			&& exit_velocity + launch_angle >= 124.0
+	}
+
+	/// Launch angle between 8 and 32 degrees.
+	#[must_use]
+	pub fn is_sweet_spot(&self) -> bool {
+		(8.0..=32.0).contains(&self.launch_angle)
+	}
+
+	/// Exit velocity of at least 95 mph.
+	#[must_use]
+	pub fn is_hard_hit(&self) -> bool {
+		self.exit_velocity >= 95.0
+	}
+}
+
+impl HitData {
+	/// [`is_barrel`](Self::is_barrel) as reported, otherwise computed from the [`StatcastHitData`].
+	#[must_use]
+	pub fn barreled(&self) -> Option<bool> {
+		self.is_barrel.or_else(|| self.statcast.as_ref().map(StatcastHitData::is_barrel))
+	}
+}
+
+impl Play {
+	/// [`HitData`] of the ball put in play, if any.
+	#[must_use]
+	pub fn hit_data(&self) -> Option<&HitData> {
+		self.play_events.iter().rev().find_map(|event| match event {
+			PlayEvent::Pitch { hit_data: Some(hit_data), details, .. } if details.is_in_play => Some(hit_data),
+			_ => None,
+		})
+	}
+
+	/// Pull, center, or opposite field for the ball put in play, see [`HitCoordinates::direction`].
+	#[must_use]
+	pub fn hit_direction(&self) -> Option<HitDirection> {
+		self.hit_data()?.coordinates?.direction(self.matchup.bat_side)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{LiveFeedRequest, StatcastHitData};
+	use crate::meta::EventType;
+	use crate::request::RequestURLBuilderExt;
+
+	#[test]
+	fn barrels() {
+		let hit = |exit_velocity, launch_angle| StatcastHitData { exit_velocity, launch_angle, distance: 0.0 };
+		assert!(hit(98.0, 28.0).is_barrel());
+		assert!(!hit(98.0, 20.0).is_barrel());
+		assert!(hit(116.0, 8.0).is_barrel());
+		assert!(hit(120.0, 50.0).is_barrel());
+		assert!(!hit(120.0, 5.0).is_barrel());
+		assert!(!hit(120.0, 51.0).is_barrel());
+	}
+
+	#[tokio::test]
+	async fn ws_gm7_2025_batted_balls() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let home_runs = feed.live.plays.iter()
+			.filter(|play| play.result.completed_play_details.as_ref().is_some_and(|details| details.event == EventType::HomeRun))
+			.collect::<Vec<_>>();
+		let batters = home_runs.iter().map(|play| play.matchup.batter.full_name.as_str()).collect::<Vec<_>>();
+		assert_eq!(batters, ["Bo Bichette", "Max Muncy", "Miguel Rojas", "Will Smith"]);
+		for play in home_runs {
+			let position = play.hit_data().unwrap().coordinates.unwrap().field_position();
+			assert!(position.distance() > 300.0, "{} homered only {:.0} ft", play.matchup.batter.full_name, position.distance());
+			assert!(position.spray_angle().abs() <= 50.0);
+			assert!(play.hit_direction().is_some());
+		}
+	}
+}
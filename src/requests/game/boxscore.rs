@@ -82,10 +82,9 @@ pub struct PlayerWithGameData {
     #[serde(rename = "parentTeamId", default)]
 	pub __parent_team_id: IgnoredAny,
 
-	// only sometimes present
-	#[doc(hidden)]
-	#[serde(rename = "boxscoreName", default)]
-	pub __boxscore_name: IgnoredAny,
+	/// Name as printed in box scores; only sometimes present.
+	#[serde(default)]
+	pub boxscore_name: Option<String>,
 }
 
 /// A team with some potentially useful information regarding their performance in the current game.
@@ -0,0 +1,324 @@
+//! Classic newspaper-style rendering of a [`Boxscore`].
+//!
+//! Batting and pitching tables for both teams, followed by the notes, the 2B/HR/SB (etc.) lines, umpires, and the game's misc info (time, attendance, weather).
+
+use std::fmt::{Display, Formatter};
+
+use crate::{game::{Boxscore, Official, PlayerWithGameData, TeamWithGameData}, stats::{derived, raw::OmittedStatError, CountingStat}};
+
+/// Output format for [`Boxscore::text`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum BoxscoreFormat {
+	/// Fixed-width text, as printed in a newspaper.
+	#[default]
+	PlainText,
+	/// Tables as fixed-width code blocks with bolded labels, renders properly in Slack as well as most Markdown viewers.
+	Markdown,
+	/// Unstyled `<table>`s and `<p>`s.
+	Html,
+}
+
+/// A [`Display`]able box score, see [`Boxscore::text`].
+#[derive(Debug, Copy, Clone)]
+pub struct BoxscoreText<'a> {
+	boxscore: &'a Boxscore,
+	format: BoxscoreFormat,
+}
+
+impl Boxscore {
+	/// Renders this boxscore as a classic newspaper box score.
+	///
+	/// ## Examples
+	/// ```no_run
+	/// let boxscore: Boxscore = ...;
+	///
+	/// println!("{}", boxscore.text(BoxscoreFormat::PlainText));
+	/// ```
+	#[must_use]
+	pub const fn text(&self, format: BoxscoreFormat) -> BoxscoreText<'_> {
+		BoxscoreText { boxscore: self, format }
+	}
+}
+
+impl Display for BoxscoreText<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let mut out = Writer { f, format: self.format };
+		for team in [&self.boxscore.teams.away, &self.boxscore.teams.home] {
+			out.table(&batting_table(team))?;
+			for note in &team.notes {
+				out.line(&format!("{}-{}", note.label.trim_end_matches('-'), note.value))?;
+			}
+			for section in &team.sectioned_labelled_values {
+				out.heading(&section.section)?;
+				for value in &section.values {
+					out.labelled(&value.label, &value.value)?;
+				}
+			}
+			out.gap()?;
+		}
+		for team in [&self.boxscore.teams.away, &self.boxscore.teams.home] {
+			out.table(&pitching_table(team))?;
+		}
+		for note in &self.boxscore.pitching_notes {
+			out.line(note)?;
+		}
+		out.gap()?;
+		self.write_misc(&mut out)
+	}
+}
+
+impl BoxscoreText<'_> {
+	fn write_misc(&self, out: &mut Writer<'_, '_>) -> std::fmt::Result {
+		if !self.boxscore.officials.is_empty() {
+			out.labelled("Umpires", &umpires(&self.boxscore.officials))?;
+		}
+		for value in self.boxscore.misc.iter().filter(|value| value.label != "Umpires") {
+			if value.value.is_empty() {
+				out.line(&value.label)?;
+			} else {
+				out.labelled(&value.label, &value.value)?;
+			}
+		}
+		Ok(())
+	}
+}
+
+/// A table where the first column is left aligned text and the rest are right aligned stats.
+struct Table {
+	header: Vec<String>,
+	rows: Vec<Vec<String>>,
+}
+
+struct Writer<'a, 'b> {
+	f: &'a mut Formatter<'b>,
+	format: BoxscoreFormat,
+}
+
+impl Writer<'_, '_> {
+	fn heading(&mut self, text: &str) -> std::fmt::Result {
+		match self.format {
+			BoxscoreFormat::PlainText => writeln!(self.f, "{text}"),
+			BoxscoreFormat::Markdown => writeln!(self.f, "**{text}**  "),
+			BoxscoreFormat::Html => writeln!(self.f, "<h4>{}</h4>", escape_html(text)),
+		}
+	}
+
+	fn line(&mut self, text: &str) -> std::fmt::Result {
+		match self.format {
+			BoxscoreFormat::PlainText => writeln!(self.f, "{text}"),
+			BoxscoreFormat::Markdown => writeln!(self.f, "{text}  "),
+			BoxscoreFormat::Html => writeln!(self.f, "<p>{}</p>", escape_html(text)),
+		}
+	}
+
+	fn labelled(&mut self, label: &str, value: &str) -> std::fmt::Result {
+		match self.format {
+			BoxscoreFormat::PlainText => writeln!(self.f, "{label}: {value}"),
+			BoxscoreFormat::Markdown => writeln!(self.f, "**{label}:** {value}  "),
+			BoxscoreFormat::Html => writeln!(self.f, "<p><b>{}:</b> {}</p>", escape_html(label), escape_html(value)),
+		}
+	}
+
+	fn gap(&mut self) -> std::fmt::Result {
+		match self.format {
+			BoxscoreFormat::PlainText | BoxscoreFormat::Markdown => writeln!(self.f),
+			BoxscoreFormat::Html => Ok(()),
+		}
+	}
+
+	fn table(&mut self, table: &Table) -> std::fmt::Result {
+		match self.format {
+			BoxscoreFormat::PlainText => self.fixed_width_table(table)?,
+			BoxscoreFormat::Markdown => {
+				writeln!(self.f, "```")?;
+				self.fixed_width_table(table)?;
+				writeln!(self.f, "```")?;
+			},
+			BoxscoreFormat::Html => self.html_table(table)?,
+		}
+		self.gap()
+	}
+
+	fn fixed_width_table(&mut self, table: &Table) -> std::fmt::Result {
+		let widths = (0..table.header.len())
+			.map(|column| std::iter::once(&table.header).chain(&table.rows).map(|row| row[column].chars().count()).max().unwrap_or(0))
+			.collect::<Vec<_>>();
+		for row in std::iter::once(&table.header).chain(&table.rows) {
+			for (column, (cell, &width)) in row.iter().zip(&widths).enumerate() {
+				if column == 0 {
+					write!(self.f, "{cell:<width$}")?;
+				} else {
+					write!(self.f, " {cell:>width$}")?;
+				}
+			}
+			writeln!(self.f)?;
+		}
+		Ok(())
+	}
+
+	fn html_table(&mut self, table: &Table) -> std::fmt::Result {
+		writeln!(self.f, "<table>")?;
+		write!(self.f, "<thead><tr>")?;
+		for cell in &table.header {
+			write!(self.f, "<th>{}</th>", escape_html(cell))?;
+		}
+		writeln!(self.f, "</tr></thead>")?;
+		writeln!(self.f, "<tbody>")?;
+		for row in &table.rows {
+			write!(self.f, "<tr>")?;
+			for cell in row {
+				write!(self.f, "<td>{}</td>", escape_html(cell))?;
+			}
+			writeln!(self.f, "</tr>")?;
+		}
+		writeln!(self.f, "</tbody>")?;
+		writeln!(self.f, "</table>")
+	}
+}
+
+fn batting_table(team: &TeamWithGameData) -> Table {
+	let mut lineup = team.batters.iter().filter_map(|id| team.players.get(id)).filter(|player| player.batting_order.is_some()).collect::<Vec<_>>();
+	lineup.sort_by_key(|player| player.batting_order.map(|order| (order.major, order.minor)));
+
+	let mut rows = lineup.into_iter().map(|player| {
+		let game = &player.game_stats.hitting;
+		let season = &player.season_stats.hitting;
+		let indent = if player.batting_order.is_some_and(|order| order.minor > 0) { " " } else { "" };
+		let positions = player.all_positions.iter().map(|position| position.abbreviation.to_lowercase()).collect::<Vec<_>>().join("-");
+		vec![
+			format!("{indent}{} {positions}", display_name(player)),
+			stat(game.at_bats), stat(game.runs), stat(game.hits), stat(game.rbi), stat(game.base_on_balls), stat(game.strikeouts),
+			derived::avg(season.hits, season.at_bats).to_string(),
+		]
+	}).collect::<Vec<_>>();
+
+	let totals = &team.team_stats.hitting;
+	rows.push(vec![
+		"Totals".to_owned(),
+		stat(totals.at_bats), stat(totals.runs), stat(totals.hits), stat(totals.rbi), stat(totals.base_on_balls), stat(totals.strikeouts),
+		String::new(),
+	]);
+
+	Table {
+		header: [team.team.full_name.as_str(), "AB", "R", "H", "BI", "BB", "SO", "AVG"].map(str::to_owned).to_vec(),
+		rows,
+	}
+}
+
+fn pitching_table(team: &TeamWithGameData) -> Table {
+	let mut rows = team.pitchers.iter().filter_map(|id| team.players.get(id)).map(|player| {
+		let game = &player.game_stats.pitching;
+		let season = &player.season_stats.pitching;
+		let name = decision(player).map_or_else(|| display_name(player).to_owned(), |decision| format!("{} {decision}", display_name(player)));
+		vec![
+			name,
+			game.innings_pitched.map_or_else(|_| "-".to_owned(), |innings_pitched| innings_pitched.to_string()),
+			stat(game.hits), stat(game.runs), stat(game.earned_runs), stat(game.base_on_balls), stat(game.strikeouts), stat(game.home_runs), stat(game.number_of_pitches),
+			derived::era(season.earned_runs, season.innings_pitched).to_string(),
+		]
+	}).collect::<Vec<_>>();
+
+	let totals = &team.team_stats.pitching;
+	rows.push(vec![
+		"Totals".to_owned(),
+		totals.innings_pitched.map_or_else(|_| "-".to_owned(), |innings_pitched| innings_pitched.to_string()),
+		stat(totals.hits), stat(totals.runs), stat(totals.earned_runs), stat(totals.base_on_balls), stat(totals.strikeouts), stat(totals.home_runs), stat(totals.number_of_pitches),
+		String::new(),
+	]);
+
+	Table {
+		header: [team.team.full_name.as_str(), "IP", "H", "R", "ER", "BB", "SO", "HR", "NP", "ERA"].map(str::to_owned).to_vec(),
+		rows,
+	}
+}
+
+/// The pitcher's decision with their updated record, `W, 3-1`, `S, 12`, etc.
+fn decision(player: &PlayerWithGameData) -> Option<String> {
+	let game = &player.game_stats.pitching;
+	let season = &player.season_stats.pitching;
+	let record = || format!("{}-{}", stat(season.wins), stat(season.losses));
+	if game.wins.is_ok_and(|wins| wins > 0) {
+		Some(format!("W, {}", record()))
+	} else if game.losses.is_ok_and(|losses| losses > 0) {
+		Some(format!("L, {}", record()))
+	} else if game.saves.is_ok_and(|saves| saves > 0) {
+		Some(format!("S, {}", stat(season.saves)))
+	} else if game.blown_saves.is_ok_and(|blown_saves| blown_saves > 0) {
+		Some(format!("BS, {}", stat(season.blown_saves)))
+	} else if game.holds.is_ok_and(|holds| holds > 0) {
+		Some(format!("H, {}", stat(season.holds)))
+	} else {
+		None
+	}
+}
+
+fn display_name(player: &PlayerWithGameData) -> &str {
+	player.boxscore_name.as_deref().unwrap_or_else(|| last_name(&player.person.full_name))
+}
+
+/// Last name from a full name, keeping generational suffixes; `"Vladimir Guerrero Jr."` is `"Guerrero Jr."`.
+fn last_name(full_name: &str) -> &str {
+	const SUFFIXES: [&str; 7] = ["Jr.", "Sr.", "Jr", "Sr", "II", "III", "IV"];
+	let mut words = full_name.rmatch_indices(' ').map(|(idx, _)| idx + 1);
+	let Some(last) = words.next() else { return full_name };
+	if SUFFIXES.contains(&&full_name[last..]) && let Some(second_last) = words.next() {
+		&full_name[second_last..]
+	} else {
+		&full_name[last..]
+	}
+}
+
+fn umpires(officials: &[Official]) -> String {
+	let umpires = officials.iter()
+		.map(|official| format!("{}: {}", official.official_type.abbreviation(), official.official.full_name))
+		.collect::<Vec<_>>()
+		.join(". ");
+	format!("{umpires}.")
+}
+
+fn stat(value: Result<CountingStat, OmittedStatError>) -> String {
+	value.map_or_else(|_| "-".to_owned(), |value| value.to_string())
+}
+
+fn escape_html(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for ch in text.chars() {
+		match ch {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			_ => escaped.push(ch),
+		}
+	}
+	escaped
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{BoxscoreFormat, BoxscoreRequest};
+	use crate::request::RequestURLBuilderExt;
+
+	#[test]
+	fn last_names() {
+		assert_eq!(super::last_name("Vladimir Guerrero Jr."), "Guerrero Jr.");
+		assert_eq!(super::last_name("Shohei Ohtani"), "Ohtani");
+		assert_eq!(super::last_name("Ichiro"), "Ichiro");
+	}
+
+	#[tokio::test]
+	async fn ws_gm7_2025_boxscore_text() {
+		let boxscore = BoxscoreRequest::builder().id(813_024).build_and_get().await.unwrap();
+		for format in [BoxscoreFormat::PlainText, BoxscoreFormat::Markdown, BoxscoreFormat::Html] {
+			let text = boxscore.text(format).to_string();
+			assert!(text.contains(&boxscore.teams.away.team.full_name));
+			assert!(text.contains(&boxscore.teams.home.team.full_name));
+			assert!(text.contains("Yamamoto W, "), "missing the winning pitcher in {text}");
+		}
+		let text = boxscore.text(BoxscoreFormat::PlainText).to_string();
+		let totals = text.lines().filter(|line| line.starts_with("Totals")).map(|line| line.split_whitespace().collect::<Vec<_>>()).collect::<Vec<_>>();
+		assert_eq!(totals.len(), 4);
+		// batting runs, away then home; pitching runs allowed, away then home
+		assert_eq!([totals[0][2], totals[1][2], totals[2][3], totals[3][3]], ["5", "4", "4", "5"]);
+	}
+}
@@ -0,0 +1,285 @@
+//! Replay and ABS challenge analytics; success rates, run value, and review times per game, team, player and umpire.
+
+use std::hash::Hash;
+
+use chrono::TimeDelta;
+use fxhash::FxHashMap;
+
+use crate::{NaiveDateRange, TeamSide, game::{GameId, LiveFeedRequest, LiveFeedResponse, OfficialType, Play, ReviewData, umpire_scorecard::call_run_swing}, meta::ReviewReasonId, person::PersonId, request::{self, RequestURLBuilderExt}, schedule::ScheduleRequest, sport::SportId, team::TeamId};
+
+/// Who or what a [`ChallengeRecord`] reviewed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ChallengeKind {
+	/// Automated Ball-Strike challenge of a called pitch by a player.
+	Abs,
+	/// Manager's replay challenge.
+	Replay,
+	/// Review initiated by the umpires, not charged to either team.
+	CrewChief,
+}
+
+/// A single review and its outcome.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChallengeRecord {
+	pub game: GameId,
+	pub at_bat_idx: usize,
+	/// Index of the reviewed event within [`Play::play_events`], `None` if the review was of the play as a whole.
+	pub play_event_idx: Option<usize>,
+	pub kind: ChallengeKind,
+	pub review_type: ReviewReasonId,
+	/// `None` for [`ChallengeKind::CrewChief`].
+	pub challenging_team: Option<TeamId>,
+	pub challenging_side: Option<TeamSide>,
+	/// Player who challenged the call, ABS only.
+	pub player: Option<PersonId>,
+	/// Home plate umpire whose call was challenged, ABS only.
+	pub umpire: Option<PersonId>,
+	pub is_overturned: bool,
+	/// Balls in the count before the reviewed event.
+	pub balls: u8,
+	/// Strikes in the count before the reviewed event.
+	pub strikes: u8,
+	/// Runs the ball/strike call was worth at the count, ABS only; not a leverage index, the game situation isn't considered.
+	pub run_value_swing: Option<f64>,
+	/// Time from the end of the reviewed event until play resumed, approximate for reviews of the play as a whole.
+	pub duration: Option<TimeDelta>,
+}
+
+/// Challenge totals for a group of [`ChallengeRecord`]s.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChallengeSummary {
+	pub challenges: usize,
+	pub overturned: usize,
+	/// Sum of the known [`ChallengeRecord::duration`]s.
+	pub review_time: TimeDelta,
+	/// Number of challenges with a known duration.
+	pub timed_challenges: usize,
+	/// Sum of the known [`ChallengeRecord::run_value_swing`]s.
+	pub run_value_swing: f64,
+	/// Number of challenges with a known run value swing.
+	pub run_valued_challenges: usize,
+}
+
+impl ChallengeSummary {
+	/// Fraction of challenges that overturned the call, `None` if there were none.
+	#[must_use]
+	#[allow(clippy::cast_precision_loss, reason = "far fewer than 2^52 challenges")]
+	pub fn success_rate(&self) -> Option<f64> {
+		(self.challenges > 0).then(|| self.overturned as f64 / self.challenges as f64)
+	}
+
+	/// Mean of the known review times, `None` if there are none.
+	#[must_use]
+	pub fn average_review_time(&self) -> Option<TimeDelta> {
+		let count = i32::try_from(self.timed_challenges).ok().filter(|&count| count > 0)?;
+		Some(self.review_time / count)
+	}
+
+	/// Mean run value of the challenged calls, `None` if there are none.
+	#[must_use]
+	#[allow(clippy::cast_precision_loss, reason = "far fewer than 2^52 challenges")]
+	pub fn average_run_value_swing(&self) -> Option<f64> {
+		(self.run_valued_challenges > 0).then(|| self.run_value_swing / self.run_valued_challenges as f64)
+	}
+
+	fn record(&mut self, challenge: &ChallengeRecord) {
+		self.challenges += 1;
+		self.overturned += usize::from(challenge.is_overturned);
+		if let Some(duration) = challenge.duration {
+			self.review_time += duration;
+			self.timed_challenges += 1;
+		}
+		if let Some(run_value_swing) = challenge.run_value_swing {
+			self.run_value_swing += run_value_swing;
+			self.run_valued_challenges += 1;
+		}
+	}
+}
+
+impl<'a> FromIterator<&'a ChallengeRecord> for ChallengeSummary {
+	fn from_iter<I: IntoIterator<Item = &'a ChallengeRecord>>(iter: I) -> Self {
+		let mut summary = Self::default();
+		for challenge in iter {
+			summary.record(challenge);
+		}
+		summary
+	}
+}
+
+/// Every review from one or more games.
+///
+/// ## Examples
+/// ```no_run
+/// let report = ChallengeReport::for_date_range(start..=end, SportId::MLB).await?;
+///
+/// for (team, summary) in report.by_team() {
+///     println!("{team}: {}/{} overturned", summary.overturned, summary.challenges);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ChallengeReport {
+	pub challenges: Vec<ChallengeRecord>,
+}
+
+impl ChallengeReport {
+	/// Every review in the `feed`.
+	#[must_use]
+	pub fn from_feed(feed: &LiveFeedResponse) -> Self {
+		let home_plate_umpire = feed.live.boxscore.officials.iter().find(|official| official.official_type == OfficialType::HomePlate).map(|official| official.official.id);
+		let mut report = Self::default();
+		for play in &feed.live.plays {
+			for (play_event_idx, event) in play.play_events.iter().enumerate() {
+				for review in &event.reviews {
+					report.challenges.push(ChallengeRecord::new(feed, play, Some(play_event_idx), review, home_plate_umpire));
+				}
+			}
+			for review in &play.reviews {
+				report.challenges.push(ChallengeRecord::new(feed, play, None, review, home_plate_umpire));
+			}
+		}
+		report
+	}
+
+	/// Every review in the finished games of a date range, for season-level reports.
+	///
+	/// # Errors
+	/// See [`request::Error`]
+	pub async fn for_date_range(range: NaiveDateRange, sport_id: SportId) -> Result<Self, request::Error> {
+		let schedule = ScheduleRequest::<()>::builder().date_range(range).sport_id(sport_id).build_and_get().await?;
+		let mut report = Self::default();
+		for game in schedule.dates.iter().flat_map(|date| &date.games) {
+			if !game.status.abstract_game_code.is_finished() {
+				continue;
+			}
+			let feed = LiveFeedRequest::builder().id(game.game_id).build_and_get().await?;
+			report.merge(Self::from_feed(&feed));
+		}
+		Ok(report)
+	}
+
+	/// Adds the challenges from `other`, such as another game.
+	pub fn merge(&mut self, other: Self) {
+		self.challenges.extend(other.challenges);
+	}
+
+	#[must_use]
+	pub fn summary(&self) -> ChallengeSummary {
+		self.challenges.iter().collect()
+	}
+
+	#[must_use]
+	pub fn by_game(&self) -> FxHashMap<GameId, ChallengeSummary> {
+		self.group_by(|challenge| Some(challenge.game))
+	}
+
+	/// Crew chief reviews are excluded.
+	#[must_use]
+	pub fn by_team(&self) -> FxHashMap<TeamId, ChallengeSummary> {
+		self.group_by(|challenge| challenge.challenging_team)
+	}
+
+	/// ABS challenges only.
+	#[must_use]
+	pub fn by_player(&self) -> FxHashMap<PersonId, ChallengeSummary> {
+		self.group_by(|challenge| challenge.player)
+	}
+
+	/// ABS challenges only.
+	#[must_use]
+	pub fn by_umpire(&self) -> FxHashMap<PersonId, ChallengeSummary> {
+		self.group_by(|challenge| challenge.umpire)
+	}
+
+	/// Keyed by (balls, strikes) before the reviewed event.
+	#[must_use]
+	pub fn by_count(&self) -> FxHashMap<(u8, u8), ChallengeSummary> {
+		self.group_by(|challenge| Some((challenge.balls, challenge.strikes)))
+	}
+
+	#[must_use]
+	pub fn by_kind(&self) -> FxHashMap<ChallengeKind, ChallengeSummary> {
+		self.group_by(|challenge| Some(challenge.kind))
+	}
+
+	/// The `count` challenges with the largest run value swing, largest first.
+	#[must_use]
+	pub fn largest_run_value_swings(&self, count: usize) -> Vec<&ChallengeRecord> {
+		let mut challenges = self.challenges.iter().filter(|challenge| challenge.run_value_swing.is_some()).collect::<Vec<_>>();
+		challenges.sort_by(|a, b| b.run_value_swing.unwrap_or_default().total_cmp(&a.run_value_swing.unwrap_or_default()));
+		challenges.truncate(count);
+		challenges
+	}
+
+	fn group_by<K: Eq + Hash>(&self, key: impl Fn(&ChallengeRecord) -> Option<K>) -> FxHashMap<K, ChallengeSummary> {
+		let mut groups = FxHashMap::<K, ChallengeSummary>::default();
+		for challenge in &self.challenges {
+			if let Some(key) = key(challenge) {
+				groups.entry(key).or_default().record(challenge);
+			}
+		}
+		groups
+	}
+}
+
+impl ChallengeRecord {
+	fn new(feed: &LiveFeedResponse, play: &Play, play_event_idx: Option<usize>, review: &ReviewData, home_plate_umpire: Option<PersonId>) -> Self {
+		// only ABS reviews name the challenging player; a replay challenge can be attached to a pitch too (the ball was put in play).
+		let kind = if review.player.is_some() {
+			ChallengeKind::Abs
+		} else if review.challenging_team.is_none() {
+			ChallengeKind::CrewChief
+		} else {
+			ChallengeKind::Replay
+		};
+		let previous_count = play.play_events[..play_event_idx.unwrap_or(play.play_events.len())].last().map(|event| event.count);
+		let (balls, strikes) = previous_count.map_or((0, 0), |count| (count.balls, count.strikes));
+		let teams = &feed.data.teams;
+		Self {
+			game: feed.id,
+			at_bat_idx: play.about.at_bat_idx,
+			play_event_idx,
+			kind,
+			review_type: review.review_type.clone(),
+			challenging_team: review.challenging_team,
+			challenging_side: review.challenging_team.and_then(|team| if team == teams.home.id { Some(TeamSide::Home) } else if team == teams.away.id { Some(TeamSide::Away) } else { None }),
+			player: review.player.as_ref().map(|player| player.id),
+			umpire: home_plate_umpire.filter(|_| kind == ChallengeKind::Abs),
+			is_overturned: review.is_overturned,
+			balls,
+			strikes,
+			run_value_swing: (kind == ChallengeKind::Abs).then(|| call_run_swing(balls, strikes)),
+			duration: review_duration(play, play_event_idx),
+		}
+	}
+}
+
+/// Time between the reviewed event ending and the next one starting, or the last event ending and the play ending for a review of the whole play.
+fn review_duration(play: &Play, play_event_idx: Option<usize>) -> Option<TimeDelta> {
+	let (reviewed, resumed) = match play_event_idx {
+		Some(idx) => (play.play_events.get(idx)?.end_timestamp, play.play_events.get(idx + 1).map_or(play.play_end_timestamp, |next| next.start_timestamp)),
+		None => (play.play_events.last()?.end_timestamp, play.play_end_timestamp),
+	};
+	Some(resumed - reviewed).filter(|duration| *duration >= TimeDelta::zero())
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{ChallengeKind, ChallengeReport, LiveFeedRequest};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_challenges() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let report = ChallengeReport::from_feed(&feed);
+		let summary = report.summary();
+		let reviews = feed.live.plays.iter().map(|play| play.reviews.len() + play.play_events.iter().map(|event| event.reviews.len()).sum::<usize>()).sum::<usize>();
+		assert_eq!(summary.challenges, reviews);
+		assert!(summary.overturned <= summary.challenges);
+		assert_eq!(summary.success_rate().is_some(), summary.challenges > 0);
+		// ABS challenges weren't in use in 2025
+		assert!(report.challenges.iter().all(|challenge| challenge.kind != ChallengeKind::Abs && challenge.game == 813_024.into()));
+		assert_eq!(summary.challenges, report.by_kind().values().map(|summary| summary.challenges).sum::<usize>());
+		let charged = feed.data.review.teams.home.used + feed.data.review.teams.away.used;
+		assert!(report.by_team().values().map(|summary| summary.challenges).sum::<usize>() >= usize::try_from(charged).unwrap());
+	}
+}
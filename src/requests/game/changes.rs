@@ -1 +1,138 @@
+//! Games that were modified (rescheduled, rescored, played, etc.) since a point in time, useful for incrementally syncing games rather than refetching every one.
 
+use std::fmt::{Display, Formatter};
+
+use bon::Builder;
+use chrono::{DateTime, NaiveDate, SecondsFormat, Utc};
+use serde::Deserialize;
+
+use crate::{Copyright, game::{GameId, LiveFeedRequest}, meta::{GameStatus, GameType}, request::RequestURL, season::SeasonId, sport::SportId};
+
+/// Games changed since [`GameChangesRequest::updated_since`], oldest date first.
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(from = "__GameChangesStruct")]
+pub struct GameChangesResponse {
+	pub copyright: Copyright,
+	pub games: Vec<ChangedGame>,
+}
+
+/// A game that changed, see [`GameChangesResponse`].
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangedGame {
+	#[serde(rename = "gamePk")]
+	pub game_id: GameId,
+	pub game_type: GameType,
+	pub season: SeasonId,
+	#[serde(deserialize_with = "crate::deserialize_datetime")]
+	pub game_date: DateTime<Utc>,
+	pub official_date: NaiveDate,
+	pub status: GameStatus,
+	/// When the game was last modified, `None` if the API omits it.
+	#[serde(rename = "lastUpdated", default, deserialize_with = "crate::try_deserialize_datetime")]
+	pub updated: Option<DateTime<Utc>>,
+}
+
+impl GameChangesResponse {
+	/// Requests to refetch the feed of every changed game.
+	pub fn live_feed_requests(&self) -> impl Iterator<Item = LiveFeedRequest> + '_ {
+		self.games.iter().map(|game| LiveFeedRequest::builder().id(game.game_id).build())
+	}
+
+	/// The most recent update of any game, a good [`GameChangesRequest::updated_since`] for the next sync.
+	///
+	/// `None` if there were no changes or the API omitted the update times, in which case use the time the request was sent instead.
+	#[must_use]
+	pub fn latest_update(&self) -> Option<DateTime<Utc>> {
+		self.games.iter().filter_map(|game| game.updated).max()
+	}
+}
+
+#[doc(hidden)]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct __GameChangesStruct {
+	#[serde(default)]
+	copyright: Copyright,
+	#[serde(default)]
+	dates: Vec<__GameChangesDateStruct>,
+}
+
+#[doc(hidden)]
+#[derive(Deserialize)]
+struct __GameChangesDateStruct {
+	games: Vec<ChangedGame>,
+}
+
+impl From<__GameChangesStruct> for GameChangesResponse {
+	fn from(value: __GameChangesStruct) -> Self {
+		Self {
+			copyright: value.copyright,
+			games: value.dates.into_iter().flat_map(|date| date.games).collect(),
+		}
+	}
+}
+
+/// Returns a [`GameChangesResponse`]
+///
+/// ## Examples
+/// ```no_run
+/// let last_sync: DateTime<Utc> = ...;
+///
+/// let changes = GameChangesRequest::builder().updated_since(last_sync).season(2025).build_and_get().await?;
+/// for request in changes.live_feed_requests() {
+///     let feed = request.get().await?;
+///     // ...
+/// }
+/// ```
+#[derive(Builder)]
+#[builder(derive(Into))]
+pub struct GameChangesRequest {
+	updated_since: DateTime<Utc>,
+	#[builder(into)]
+	#[builder(default)]
+	sport_id: SportId,
+	#[builder(into)]
+	season: Option<SeasonId>,
+	game_type: Option<GameType>,
+}
+
+impl<S: game_changes_request_builder::State + game_changes_request_builder::IsComplete> crate::request::RequestURLBuilderExt for GameChangesRequestBuilder<S> {
+	type Built = GameChangesRequest;
+}
+
+impl Display for GameChangesRequest {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(
+			f,
+			"http://statsapi.mlb.com/api/v1/game/changes{}",
+			gen_params! {
+				"updatedSince": self.updated_since.to_rfc3339_opts(SecondsFormat::Secs, true),
+				"sportId": self.sport_id,
+				"season"?: self.season,
+				"gameType"?: self.game_type.map(|game_type| format!("{game_type:?}")),
+			}
+		)
+	}
+}
+
+impl RequestURL for GameChangesRequest {
+	type Response = GameChangesResponse;
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{TimeDelta, Utc};
+
+	use crate::game::GameChangesRequest;
+	use crate::request::RequestURLBuilderExt;
+	use crate::sport::SportId;
+
+	#[tokio::test]
+	async fn last_week_changes() {
+		let since = Utc::now() - TimeDelta::days(7);
+		let changes = GameChangesRequest::builder().updated_since(since).sport_id(SportId::MLB).build_and_get().await.unwrap();
+		assert_eq!(changes.live_feed_requests().count(), changes.games.len());
+		assert!(changes.latest_update().is_none_or(|updated| updated >= since));
+	}
+}
@@ -1 +1,423 @@
+//! Editorial content regarding games; highlight clips, recap/preview/wrap articles, and the media (broadcast) guide.
 
+use std::fmt::{Display, Formatter};
+
+use bon::Builder;
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+use serde_with::{serde_as, DefaultOnError, DisplayFromStr, PickFirst};
+use uuid::Uuid;
+
+use crate::{Copyright, HomeAway, game::{GameId, Play, PlayEvent, Plays}, person::PersonId, request::RequestURL, team::TeamId};
+
+/// See [`self`]
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(from = "__GameContentStruct")]
+pub struct GameContentResponse {
+	pub copyright: Copyright,
+	pub editorial: Editorial,
+	pub media: GameMedia,
+	/// Highlight clips of the game, in no particular order.
+	pub highlights: Vec<Highlight>,
+	pub summary: ContentSummary,
+}
+
+impl GameContentResponse {
+	/// Highlights of `play`, see [`Highlight::play`].
+	pub fn highlights_of<'a>(&'a self, plays: &'a Plays, play: &'a Play) -> impl Iterator<Item = &'a Highlight> + 'a {
+		self.highlights.iter().filter(move |highlight| highlight.play(plays).is_some_and(|highlighted| highlighted.about.at_bat_idx == play.about.at_bat_idx))
+	}
+
+	/// Each scoring play and its first highlight clip, if there is one.
+	#[must_use]
+	pub fn scoring_play_highlights<'a>(&'a self, plays: &'a Plays) -> Vec<(&'a Play, Option<&'a Highlight>)> {
+		plays.scoring_plays().map(|play| (play, self.highlights_of(plays, play).next())).collect()
+	}
+}
+
+/// Articles written about the game.
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Default)]
+#[serde(from = "__EditorialStruct")]
+pub struct Editorial {
+	pub preview: Option<Article>,
+	/// Recaps from each team's site.
+	pub team_recaps: HomeAway<Option<Article>>,
+	/// Recap from MLB.com.
+	pub recap: Option<Article>,
+	pub wrap: Option<Article>,
+	pub articles: Vec<Article>,
+}
+
+/// An editorial article, such as a preview or recap.
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Article {
+	#[serde(default)]
+	pub id: Option<String>,
+	pub headline: String,
+	#[serde(default)]
+	pub subhead: Option<String>,
+	/// Short summary of the article.
+	#[serde(default)]
+	pub blurb: Option<String>,
+	#[serde(default, deserialize_with = "crate::try_deserialize_datetime")]
+	pub date: Option<DateTime<Utc>>,
+	#[serde(default)]
+	pub slug: Option<String>,
+	/// HTML body of the article.
+	#[serde(default)]
+	pub body: Option<String>,
+	#[serde(rename = "keywordsAll", default)]
+	pub keywords: Vec<Keyword>,
+}
+
+/// A highlight clip.
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Highlight {
+	pub id: String,
+	/// Identifier shared with the [`PlayEvent`] the clip depicts, when it depicts one.
+	#[serde(default)]
+	pub guid: Option<Uuid>,
+	pub headline: String,
+	#[serde(default)]
+	pub blurb: Option<String>,
+	#[serde(default)]
+	pub description: Option<String>,
+	#[serde(default, deserialize_with = "crate::try_deserialize_datetime")]
+	pub date: Option<DateTime<Utc>>,
+	#[serde(default, deserialize_with = "deserialize_optional_time_delta")]
+	pub duration: Option<TimeDelta>,
+	#[serde(rename = "keywordsAll", default)]
+	pub keywords: Vec<Keyword>,
+	/// The same clip in different encodings and resolutions.
+	#[serde(default)]
+	pub playbacks: Vec<Playback>,
+}
+
+impl Highlight {
+	/// Players tagged in the clip.
+	pub fn players(&self) -> impl Iterator<Item = PersonId> + '_ {
+		self.keywords.iter().filter_map(|keyword| match keyword {
+			Keyword::Player(player) => Some(*player),
+			_ => None,
+		})
+	}
+
+	/// Teams tagged in the clip.
+	pub fn teams(&self) -> impl Iterator<Item = TeamId> + '_ {
+		self.keywords.iter().filter_map(|keyword| match keyword {
+			Keyword::Team(team) => Some(*team),
+			_ => None,
+		})
+	}
+
+	/// [`PlayAbout::at_bat_idx`](crate::game::PlayAbout::at_bat_idx) of the play tagged in the clip.
+	#[must_use]
+	pub fn at_bat_idx(&self) -> Option<usize> {
+		self.keywords.iter().find_map(|keyword| match keyword {
+			Keyword::AtBat(at_bat_idx) => Some(*at_bat_idx),
+			_ => None,
+		})
+	}
+
+	/// The [`Play`] the clip depicts; by the tagged at bat, otherwise by the [`guid`](Self::guid) of one of its events.
+	///
+	/// `None` for clips not of a single play, such as interviews or game recaps.
+	#[must_use]
+	pub fn play<'a>(&self, plays: &'a Plays) -> Option<&'a Play> {
+		if let Some(at_bat_idx) = self.at_bat_idx() {
+			return plays.iter().find(|play| play.about.at_bat_idx == at_bat_idx);
+		}
+		let guid = self.guid?;
+		plays.iter().find(|play| play.play_events.iter().any(|event| event_play_id(event) == Some(guid)))
+	}
+
+	/// Playback with the given encoding name, ex: `"mp4Avc"` or `"hlsCloud"`.
+	#[must_use]
+	pub fn playback(&self, name: &str) -> Option<&Playback> {
+		self.playbacks.iter().find(|playback| playback.name == name)
+	}
+
+	/// The highest resolution mp4 playback, the most widely embeddable.
+	#[must_use]
+	pub fn best_mp4(&self) -> Option<&Playback> {
+		self.playbacks.iter().filter(|playback| std::path::Path::new(&playback.url).extension().is_some_and(|extension| extension.eq_ignore_ascii_case("mp4"))).max_by_key(|playback| playback.width.unwrap_or(0))
+	}
+}
+
+/// A playable encoding of a [`Highlight`].
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Playback {
+	/// Encoding name, ex: `"mp4Avc"`.
+	pub name: String,
+	pub url: String,
+	#[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+	#[serde(default)]
+	pub width: Option<u32>,
+	#[serde_as(as = "Option<PickFirst<(_, DisplayFromStr)>>")]
+	#[serde(default)]
+	pub height: Option<u32>,
+}
+
+/// A tag on a [`Highlight`] or [`Article`].
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(from = "__KeywordStruct")]
+pub enum Keyword {
+	Game(GameId),
+	Team(TeamId),
+	Player(PersonId),
+	/// A [`PlayAbout::at_bat_idx`](crate::game::PlayAbout::at_bat_idx).
+	AtBat(usize),
+	/// A topic, ex: `"home-run"` or `"interview"`.
+	Taxonomy(String),
+	Other {
+		kind: String,
+		value: String,
+		display_name: Option<String>,
+	},
+}
+
+#[doc(hidden)]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct __KeywordStruct {
+	#[serde(rename = "type")]
+	kind: String,
+	value: String,
+	#[serde(default)]
+	display_name: Option<String>,
+}
+
+impl From<__KeywordStruct> for Keyword {
+	fn from(__KeywordStruct { kind, value, display_name }: __KeywordStruct) -> Self {
+		let parsed = match kind.as_str() {
+			"game_pk" => value.parse().ok().map(|id| Self::Game(GameId::new(id))),
+			"team_id" => value.parse().ok().map(|id| Self::Team(TeamId::new(id))),
+			"player_id" => value.parse().ok().map(|id| Self::Player(PersonId::new(id))),
+			"at_bat_index" => value.parse().ok().map(Self::AtBat),
+			"taxonomy" => Some(Self::Taxonomy(value.clone())),
+			_ => None,
+		};
+		parsed.unwrap_or(Self::Other { kind, value, display_name })
+	}
+}
+
+/// The media guide of the game.
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct GameMedia {
+	/// Electronic program guide, grouped by kind of broadcast (ex: `"MLBTV"`, `"Audio"`).
+	#[serde(default)]
+	pub epg: Vec<EpgSection>,
+	#[serde(default)]
+	pub free_game: bool,
+	#[serde(default)]
+	pub enhanced_game: bool,
+}
+
+impl GameMedia {
+	/// The section of the guide with the given title, ex: `"MLBTV"`.
+	#[must_use]
+	pub fn section(&self, title: &str) -> Option<&EpgSection> {
+		self.epg.iter().find(|section| section.title == title)
+	}
+}
+
+/// Broadcasts of one kind, see [`GameMedia::epg`].
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EpgSection {
+	pub title: String,
+	#[serde(default)]
+	pub items: Vec<MediaItem>,
+}
+
+/// A single broadcast of the game.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaItem {
+	#[serde(default)]
+	pub content_id: Option<String>,
+	#[serde(default)]
+	pub media_id: Option<String>,
+	/// ex: `"MEDIA_ARCHIVE"`.
+	#[serde(default)]
+	pub media_state: Option<String>,
+	/// ex: `"HOME"`, `"AWAY"` or `"NATIONAL"`.
+	#[serde(default)]
+	pub media_feed_type: Option<String>,
+	/// Station, ex: `"SNLA"`.
+	#[serde(default)]
+	pub call_letters: Option<String>,
+	#[serde(default)]
+	pub language: Option<String>,
+	#[serde_as(deserialize_as = "DefaultOnError")]
+	#[serde(default)]
+	pub free_game: bool,
+}
+
+/// Which kinds of content exist for the game.
+#[allow(clippy::struct_excessive_bools, reason = "independent flags")]
+#[derive(Debug, Deserialize, PartialEq, Eq, Copy, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ContentSummary {
+	#[serde(default)]
+	pub has_preview_article: bool,
+	#[serde(default)]
+	pub has_recap_article: bool,
+	#[serde(default)]
+	pub has_wrap_article: bool,
+	#[serde(default)]
+	pub has_highlights_video: bool,
+}
+
+#[doc(hidden)]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
+struct __GameContentStruct {
+	#[serde(default)]
+	copyright: Copyright,
+	#[serde(default)]
+	editorial: Editorial,
+	#[serde(default)]
+	media: GameMedia,
+	#[serde(default)]
+	highlights: __HighlightsStruct,
+	#[serde(default)]
+	summary: ContentSummary,
+
+	#[serde(rename = "link", default)]
+	__link: IgnoredAny,
+	#[serde(rename = "gameNotes", default)]
+	__game_notes: IgnoredAny,
+}
+
+#[doc(hidden)]
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct __HighlightsStruct {
+	#[serde(default)]
+	highlights: Option<__ItemsStruct<Highlight>>,
+}
+
+#[doc(hidden)]
+#[derive(Deserialize)]
+struct __ItemsStruct<T> {
+	#[serde(default = "Vec::new")]
+	items: Vec<T>,
+}
+
+impl From<__GameContentStruct> for GameContentResponse {
+	fn from(value: __GameContentStruct) -> Self {
+		Self {
+			copyright: value.copyright,
+			editorial: value.editorial,
+			media: value.media,
+			highlights: value.highlights.highlights.map(|highlights| highlights.items).unwrap_or_default(),
+			summary: value.summary,
+		}
+	}
+}
+
+#[doc(hidden)]
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct __EditorialStruct {
+	#[serde(default)]
+	preview: Option<__ItemsStruct<Article>>,
+	#[serde(default)]
+	recap: Option<__RecapStruct>,
+	#[serde(default)]
+	wrap: Option<__ItemsStruct<Article>>,
+	#[serde(default)]
+	articles: Option<__ItemsStruct<Article>>,
+}
+
+#[doc(hidden)]
+#[serde_as]
+#[derive(Deserialize)]
+struct __RecapStruct {
+	#[serde_as(deserialize_as = "DefaultOnError")]
+	#[serde(default)]
+	home: Option<Article>,
+	#[serde_as(deserialize_as = "DefaultOnError")]
+	#[serde(default)]
+	away: Option<Article>,
+	#[serde_as(deserialize_as = "DefaultOnError")]
+	#[serde(default)]
+	mlb: Option<Article>,
+}
+
+impl From<__EditorialStruct> for Editorial {
+	fn from(value: __EditorialStruct) -> Self {
+		let first = |section: Option<__ItemsStruct<Article>>| section.and_then(|section| section.items.into_iter().next());
+		let recap = value.recap.unwrap_or(__RecapStruct { home: None, away: None, mlb: None });
+		Self {
+			preview: first(value.preview),
+			team_recaps: HomeAway::new(recap.home, recap.away),
+			recap: recap.mlb,
+			wrap: first(value.wrap),
+			articles: value.articles.map(|articles| articles.items).unwrap_or_default(),
+		}
+	}
+}
+
+fn deserialize_optional_time_delta<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<TimeDelta>, D::Error> {
+	#[derive(Deserialize)]
+	struct Wrapper(#[serde(deserialize_with = "crate::deserialize_time_delta_from_hms")] TimeDelta);
+
+	Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(duration)| duration))
+}
+
+/// Identifier shared with highlight clips.
+const fn event_play_id(event: &PlayEvent) -> Option<Uuid> {
+	match event {
+		PlayEvent::Pitch { play_id, .. } => Some(*play_id),
+		PlayEvent::Action { play_id, .. } | PlayEvent::Stepoff { play_id, .. } | PlayEvent::NoPitch { play_id, .. } | PlayEvent::Pickoff { play_id, .. } => *play_id,
+	}
+}
+
+/// Returns a [`GameContentResponse`]
+#[derive(Builder)]
+#[builder(derive(Into))]
+pub struct GameContentRequest {
+	#[builder(into)]
+	id: GameId,
+}
+
+impl<S: game_content_request_builder::State + game_content_request_builder::IsComplete> crate::request::RequestURLBuilderExt for GameContentRequestBuilder<S> {
+	type Built = GameContentRequest;
+}
+
+impl Display for GameContentRequest {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "http://statsapi.mlb.com/api/v1/game/{}/content", self.id)
+	}
+}
+
+impl RequestURL for GameContentRequest {
+	type Response = GameContentResponse;
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{GameContentRequest, LiveFeedRequest};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_content() {
+		let content = GameContentRequest::builder().id(813_024).build_and_get().await.unwrap();
+		assert_ne!(content.highlights.len(), 0);
+		assert!(content.highlights.iter().all(|highlight| highlight.playbacks.iter().all(|playback| !playback.url.is_empty())));
+
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let scoring_plays = content.scoring_play_highlights(&feed.live.plays);
+		assert!(scoring_plays.iter().any(|(_, highlight)| highlight.is_some()));
+	}
+}
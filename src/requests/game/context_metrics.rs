@@ -1 +1,172 @@
+//! Various metrics for game plays & play events; win probability, leverage, and expected outcomes of the current situation.
+//!
+//! For the whole game's worth of win probability, see [`WinProbabilityRequest`](crate::game::WinProbabilityRequest).
 
+use std::fmt::{Display, Formatter};
+
+use bon::Builder;
+use serde::Deserialize;
+
+use crate::{HomeAway, game::{GameId, Play, Plays, SimplifiedTimestamp}, request::RequestURL};
+
+/// Metrics of the game's current situation (or the situation at the requested timecode).
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(from = "__GameContextMetricsStruct")]
+pub struct GameContextMetricsResponse {
+	pub game: GameId,
+	/// Probability of each team winning, from 0 to 1.
+	pub win_probability: HomeAway<f64>,
+	/// Change in the home team's win probability over the current play, from -1 to 1.
+	pub home_win_probability_added: Option<f64>,
+	/// How much the current play could swing the game compared to an average play, 1 is average.
+	pub leverage_index: Option<f64>,
+	/// Runs the batting team is expected to score through the end of the inning.
+	pub expected_runs: Option<f64>,
+	/// Expected outcome of the current batter based on the quality of contact.
+	pub expected_statistics: ExpectedStatistics,
+	/// Probability a fly ball to each outfield position scores the runner from third.
+	pub sac_fly_probability: OutfieldSacFlyProbability,
+	/// The play the metrics are of, `None` before the game starts.
+	pub current_play: Option<Play>,
+}
+
+impl GameContextMetricsResponse {
+	/// The [`Play`] in `plays` the metrics are of, by at bat index.
+	#[must_use]
+	pub fn play<'a>(&self, plays: &'a Plays) -> Option<&'a Play> {
+		let at_bat_idx = self.current_play.as_ref()?.about.at_bat_idx;
+		plays.iter().find(|play| play.about.at_bat_idx == at_bat_idx)
+			.or_else(|| plays.current_play.as_ref().filter(|play| play.about.at_bat_idx == at_bat_idx))
+	}
+}
+
+/// Expected (quality of contact) batting statistics, empty when there's no batted ball to base them on.
+#[derive(Debug, Deserialize, PartialEq, Copy, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
+pub struct ExpectedStatistics {
+	#[serde(rename = "avg", default)]
+	pub batting_average: Option<f64>,
+	#[serde(rename = "slg", default)]
+	pub slugging: Option<f64>,
+	#[serde(rename = "woba", default)]
+	pub woba: Option<f64>,
+	/// wOBA on contact.
+	#[serde(rename = "wobacon", alias = "wobaCon", default)]
+	pub woba_on_contact: Option<f64>,
+}
+
+/// Sacrifice fly probability by outfield position, from 0 to 1; `None` when there isn't a runner on third with less than two outs.
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct OutfieldSacFlyProbability {
+	pub left_field: Option<f64>,
+	pub center_field: Option<f64>,
+	pub right_field: Option<f64>,
+}
+
+#[doc(hidden)]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
+struct __GameContextMetricsStruct {
+	game: __GameStruct,
+	home_win_probability: f64,
+	away_win_probability: f64,
+	#[serde(default)]
+	home_team_win_probability_added: Option<f64>,
+	#[serde(default)]
+	leverage_index: Option<f64>,
+	#[serde(default)]
+	expected_runs: Option<f64>,
+	#[serde(rename = "expectedStatisticsData", default)]
+	expected_statistics: ExpectedStatistics,
+	#[serde(default)]
+	left_field_sac_fly_probability: __ProbabilityStruct,
+	#[serde(default)]
+	center_field_sac_fly_probability: __ProbabilityStruct,
+	#[serde(default)]
+	right_field_sac_fly_probability: __ProbabilityStruct,
+	#[serde(default)]
+	current_play: Option<Play>,
+}
+
+// the rest of the fields are those of a `ScheduleGame`, which this object isn't always complete enough to deserialize as.
+#[doc(hidden)]
+#[derive(Deserialize)]
+struct __GameStruct {
+	#[serde(rename = "gamePk")]
+	id: GameId,
+}
+
+#[doc(hidden)]
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
+struct __ProbabilityStruct {
+	#[serde(default)]
+	probability: Option<f64>,
+}
+
+impl From<__GameContextMetricsStruct> for GameContextMetricsResponse {
+	fn from(value: __GameContextMetricsStruct) -> Self {
+		Self {
+			game: value.game.id,
+			win_probability: HomeAway::new(value.home_win_probability / 100.0, value.away_win_probability / 100.0),
+			home_win_probability_added: value.home_team_win_probability_added.map(|added| added / 100.0),
+			leverage_index: value.leverage_index,
+			expected_runs: value.expected_runs,
+			expected_statistics: value.expected_statistics,
+			sac_fly_probability: OutfieldSacFlyProbability {
+				left_field: value.left_field_sac_fly_probability.probability,
+				center_field: value.center_field_sac_fly_probability.probability,
+				right_field: value.right_field_sac_fly_probability.probability,
+			},
+			current_play: value.current_play,
+		}
+	}
+}
+
+/// Returns a [`GameContextMetricsResponse`]
+#[derive(Builder)]
+#[builder(derive(Into))]
+pub struct GameContextMetricsRequest {
+	#[builder(into)]
+	id: GameId,
+	/// Metrics as of this moment in the game rather than now, see [`GameTimestampsRequest`](crate::game::GameTimestampsRequest).
+	#[builder(into)]
+	timecode: Option<SimplifiedTimestamp>,
+}
+
+impl<S: game_context_metrics_request_builder::State + game_context_metrics_request_builder::IsComplete> crate::request::RequestURLBuilderExt for GameContextMetricsRequestBuilder<S> {
+	type Built = GameContextMetricsRequest;
+}
+
+impl Display for GameContextMetricsRequest {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "http://statsapi.mlb.com/api/v1/game/{}/contextMetrics{}", self.id, gen_params! { "timecode"?: self.timecode.as_ref() })
+	}
+}
+
+impl RequestURL for GameContextMetricsRequest {
+	type Response = GameContextMetricsResponse;
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{GameContextMetricsRequest, GameTimestampsRequest, LiveFeedRequest};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_context_metrics() {
+		let metrics = GameContextMetricsRequest::builder().id(813_024).build_and_get().await.unwrap();
+		assert!((metrics.win_probability.home + metrics.win_probability.away - 1.0).abs() < 0.01);
+
+		let timestamps = GameTimestampsRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let midgame = timestamps[timestamps.len() / 2].clone();
+		let metrics = GameContextMetricsRequest::builder().id(813_024).timecode(midgame).build_and_get().await.unwrap();
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		if let Some(current_play) = &metrics.current_play {
+			assert_eq!(metrics.play(&feed.live.plays).map(|play| play.about.at_bat_idx), Some(current_play.about.at_bat_idx));
+		}
+	}
+}
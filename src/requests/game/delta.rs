@@ -0,0 +1,180 @@
+//! What changed between two polls of the same game, see [`LiveFeedDelta`].
+
+use fxhash::FxHashMap;
+
+use crate::{HomeAway, game::{BoxscoreStatCollection, GameId, LiveFeedResponse, Play, PlayEvent, PlayerWithGameData}, meta::{EventType, GameStatus}, person::PersonId};
+
+/// A value that differs between two snapshots.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Change<T> {
+	pub before: T,
+	pub after: T,
+}
+
+impl<T: PartialEq> Change<T> {
+	/// `None` if nothing changed.
+	fn of(before: T, after: T) -> Option<Self> {
+		(before != after).then_some(Self { before, after })
+	}
+}
+
+/// A [`PlayEvent`] and its position in the game.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PlayEventRef<'a> {
+	pub at_bat_idx: usize,
+	/// Index of the event within [`Play::play_events`].
+	pub play_event_idx: usize,
+	pub event: &'a PlayEvent,
+}
+
+/// A completed play whose result was edited after the fact, such as a hit changed to an error or a change in RBIs or earned runs.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ScoringCorrection<'a> {
+	pub at_bat_idx: usize,
+	pub before: &'a Play,
+	pub after: &'a Play,
+}
+
+impl ScoringCorrection<'_> {
+	/// The changed result of the play, `None` if the event type is unchanged (ex: only the RBIs or credited fielders changed).
+	#[must_use]
+	pub fn event(&self) -> Option<Change<EventType>> {
+		let event = |play: &Play| play.result.completed_play_details.as_ref().map(|details| details.event);
+		Change::of(event(self.before)?, event(self.after)?)
+	}
+}
+
+/// A player whose game stats moved.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StatLineChange<'a> {
+	pub player: PersonId,
+	/// `None` if the player wasn't in the game before.
+	pub before: Option<&'a BoxscoreStatCollection>,
+	pub after: &'a BoxscoreStatCollection,
+}
+
+/// The typed difference between two [`LiveFeedResponse`]s of the same game.
+///
+/// Unlike [`PlayStream`](crate::game::PlayStream), which only looks at plays past its cursor, this also catches official scoring changes to plays that were already complete.
+///
+/// ## Examples
+/// ```no_run
+/// let before = LiveFeedRequest::builder().id(game).build_and_get().await?;
+/// // ... some time later
+/// let after = LiveFeedRequest::builder().id(game).build_and_get().await?;
+///
+/// let delta = LiveFeedDelta::new(&before, &after);
+/// for correction in &delta.scoring_corrections {
+///     if let Some(change) = correction.event() {
+///         println!("scoring change: {} -> {}", change.before, change.after);
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveFeedDelta<'a> {
+	pub game: GameId,
+	pub status: Option<Change<&'a GameStatus>>,
+	/// Runs scored.
+	pub score: Option<Change<HomeAway<usize>>>,
+	/// Plays that weren't present before.
+	pub new_plays: Vec<&'a Play>,
+	/// Events that weren't present before, including the events of [`new_plays`](Self::new_plays).
+	pub new_play_events: Vec<PlayEventRef<'a>>,
+	/// Plays that were in progress before and are now complete, or are new and complete.
+	pub completed_plays: Vec<&'a Play>,
+	pub scoring_corrections: Vec<ScoringCorrection<'a>>,
+	/// Players whose boxscore game stats changed.
+	pub stat_lines: Vec<StatLineChange<'a>>,
+}
+
+impl<'a> LiveFeedDelta<'a> {
+	/// Changes from `before` to `after`, which should be two snapshots of the same game in chronological order.
+	#[must_use]
+	pub fn new(before: &'a LiveFeedResponse, after: &'a LiveFeedResponse) -> Self {
+		let mut delta = Self {
+			game: after.id,
+			status: Change::of(&before.data.status, &after.data.status),
+			score: Change::of(before.live.linescore.rhe_totals.map(|rhe| rhe.runs), after.live.linescore.rhe_totals.map(|rhe| rhe.runs)),
+			new_plays: Vec::new(),
+			new_play_events: Vec::new(),
+			completed_plays: Vec::new(),
+			scoring_corrections: Vec::new(),
+			stat_lines: stat_line_changes(before, after),
+		};
+		let before_plays = before.live.plays.iter().map(|play| (play.about.at_bat_idx, play)).collect::<FxHashMap<_, _>>();
+		for play in &after.live.plays {
+			delta.compare_play(before_plays.get(&play.about.at_bat_idx).copied(), play);
+		}
+		delta
+	}
+
+	fn compare_play(&mut self, before: Option<&'a Play>, after: &'a Play) {
+		let at_bat_idx = after.about.at_bat_idx;
+		let known_events = before.map_or(0, |before| before.play_events.len());
+		self.new_play_events.extend(after.play_events.iter().enumerate().skip(known_events).map(|(play_event_idx, event)| PlayEventRef { at_bat_idx, play_event_idx, event }));
+		match before {
+			None => self.new_plays.push(after),
+			Some(before) if before.about.is_complete && after.about.is_complete && (before.result != after.result || before.runners != after.runners) => {
+				self.scoring_corrections.push(ScoringCorrection { at_bat_idx, before, after });
+			},
+			Some(_) => {},
+		}
+		if after.about.is_complete && !before.is_some_and(|before| before.about.is_complete) {
+			self.completed_plays.push(after);
+		}
+	}
+
+	/// Whether the two snapshots are equivalent.
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.status.is_none() && self.score.is_none() && self.new_play_events.is_empty() && self.new_plays.is_empty() && self.completed_plays.is_empty() && self.scoring_corrections.is_empty() && self.stat_lines.is_empty()
+	}
+
+	/// New pitches, see [`new_play_events`](Self::new_play_events).
+	pub fn new_pitches(&self) -> impl Iterator<Item = &PlayEventRef<'a>> {
+		self.new_play_events.iter().filter(|event_ref| matches!(event_ref.event, PlayEvent::Pitch { .. }))
+	}
+
+	/// New pitching changes, pinch hitters, defensive switches, etc.
+	pub fn substitutions(&self) -> impl Iterator<Item = &PlayEventRef<'a>> {
+		self.new_play_events.iter().filter(|event_ref| event_ref.event.is_substitution)
+	}
+}
+
+fn stat_line_changes<'a>(before: &'a LiveFeedResponse, after: &'a LiveFeedResponse) -> Vec<StatLineChange<'a>> {
+	let players = |feed: &'a LiveFeedResponse| feed.live.boxscore.teams.as_ref().map(|team| &team.players);
+	let before_players = players(before);
+	let after_players = players(after);
+	[(before_players.home, after_players.home), (before_players.away, after_players.away)].into_iter()
+		.flat_map(|(before_players, after_players)| after_players.iter().filter_map(move |(&player, after): (&PersonId, &'a PlayerWithGameData)| {
+			let before = before_players.get(&player).map(|before| &before.game_stats);
+			(before != Some(&after.game_stats)).then_some(StatLineChange { player, before, after: &after.game_stats })
+		}))
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{LiveFeedDelta, LiveFeedRequest, LiveFeedResponse};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_delta() {
+		let request = LiveFeedRequest::builder().id(813_024).build();
+		let mut json = crate::request::get::<serde_json::Value>(request.to_string()).await.unwrap();
+		let after = serde_json::from_value::<LiveFeedResponse>(json.clone()).unwrap();
+		assert!(LiveFeedDelta::new(&after, &after).is_empty());
+
+		let plays = json["liveData"]["plays"]["allPlays"].as_array_mut().unwrap();
+		plays.truncate(plays.len() - 3);
+		plays[0]["result"]["rbi"] = (plays[0]["result"]["rbi"].as_u64().unwrap() + 1).into();
+		let before = serde_json::from_value::<LiveFeedResponse>(json).unwrap();
+		let delta = LiveFeedDelta::new(&before, &after);
+		assert_eq!(delta.new_plays.len(), 3);
+		assert_eq!(delta.completed_plays.len(), 3);
+		assert_eq!(delta.new_play_events.len(), after.live.plays.iter().rev().take(3).map(|play| play.play_events.len()).sum::<usize>());
+		assert_eq!(delta.scoring_corrections.len(), 1);
+		assert_eq!(delta.scoring_corrections[0].at_bat_idx, 0);
+		assert!(delta.scoring_corrections[0].event().is_none());
+	}
+}
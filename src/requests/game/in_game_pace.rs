@@ -0,0 +1,225 @@
+//! Pace of a single game computed from its play timestamps; time between pitches, per plate appearance and inning, and dead time.
+//!
+//! For season-wide averages, see [`SportGamePaceRequest`](crate::game::SportGamePaceRequest) and friends.
+
+use chrono::TimeDelta;
+use fxhash::FxHashSet;
+
+use crate::{game::{Inning, Play, PlayEvent, Plays, pace::divide}, meta::EventType};
+
+/// Pace of a single game, see [`Plays::pace`].
+///
+/// Totals share the names and meaning of their [`GamePace`](crate::game::GamePace) counterparts, as do the `time_per_*` methods.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct InGamePace {
+	/// First play's start to the last play's end, including any delays.
+	pub total_game_time: TimeDelta,
+	/// Has a .5 for a half-inning played
+	pub innings_played: f64,
+	pub hits: usize,
+	pub runs: usize,
+	pub plate_appearances: usize,
+	pub num_pitchers: usize,
+	pub num_pitches: usize,
+	/// Time between consecutive pitches by the same pitcher in the same plate appearance (start to start), where the bases were empty before the second pitch.
+	pub pitch_intervals_bases_empty: Vec<TimeDelta>,
+	/// Same as [`Self::pitch_intervals_bases_empty`], with at least one runner on base.
+	pub pitch_intervals_runners_on: Vec<TimeDelta>,
+	/// Start to end of each plate appearance.
+	pub plate_appearance_times: Vec<TimeDelta>,
+	/// Start of each inning's first play to the end of its last, including the break between halves.
+	pub inning_times: Vec<(Inning, TimeDelta)>,
+	pub delays: Vec<PaceDelay>,
+}
+
+/// Dead time in a game, see [`InGamePace::delays`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaceDelay {
+	pub kind: PaceDelayKind,
+	pub at_bat_idx: usize,
+	/// Index into [`Play::play_events`], `None` for a review of the play as a whole.
+	pub play_event_idx: Option<usize>,
+	/// End of the event before to the start of the event after; for the first event of a play, from the end of the previous play in the half-inning.
+	///
+	/// `None` for a review of the last play of a half-inning, which can't be told apart from the break between halves.
+	pub duration: Option<TimeDelta>,
+}
+
+/// Cause of a [`PaceDelay`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PaceDelayKind {
+	/// A manager challenge, crew chief review, or ABS challenge.
+	Review,
+	Injury,
+	PitchingChange,
+}
+
+impl InGamePace {
+	/// Mean of [`Self::pitch_intervals_bases_empty`], `None` if there are none.
+	#[must_use]
+	pub fn time_between_pitches_bases_empty(&self) -> Option<TimeDelta> {
+		average(&self.pitch_intervals_bases_empty)
+	}
+
+	/// Mean of [`Self::pitch_intervals_runners_on`], `None` if there are none.
+	#[must_use]
+	pub fn time_between_pitches_runners_on(&self) -> Option<TimeDelta> {
+		average(&self.pitch_intervals_runners_on)
+	}
+
+	/// [`Self::total_game_time`] over [`Self::plate_appearances`], as in [`GamePace::time_per_plate_appearance`](crate::game::GamePace::time_per_plate_appearance).
+	#[must_use]
+	pub fn time_per_plate_appearance(&self) -> Option<TimeDelta> {
+		divide(self.total_game_time, self.plate_appearances)
+	}
+
+	/// [`Self::total_game_time`] over [`Self::num_pitches`], as in [`GamePace::time_per_pitch`](crate::game::GamePace::time_per_pitch).
+	#[must_use]
+	pub fn time_per_pitch(&self) -> Option<TimeDelta> {
+		divide(self.total_game_time, self.num_pitches)
+	}
+
+	/// Mean of [`Self::inning_times`], `None` if there are none.
+	#[must_use]
+	pub fn time_per_inning(&self) -> Option<TimeDelta> {
+		divide(self.inning_times.iter().map(|(_, time)| *time).sum(), self.inning_times.len())
+	}
+
+	/// Total dead time of the given kind.
+	#[must_use]
+	pub fn delay(&self, kind: PaceDelayKind) -> TimeDelta {
+		self.delays.iter().filter(|delay| delay.kind == kind).filter_map(|delay| delay.duration).sum()
+	}
+
+	/// Total dead time of every kind.
+	#[must_use]
+	pub fn total_delay(&self) -> TimeDelta {
+		self.delays.iter().filter_map(|delay| delay.duration).sum()
+	}
+}
+
+impl Plays {
+	/// Pace of the game, from the timestamps of its plays and play events.
+	///
+	/// ## Examples
+	/// ```no_run
+	/// let plays: Plays = ...;
+	///
+	/// let pace = plays.pace();
+	/// println!("{:?} between pitches with the bases empty, {:?} with runners on", pace.time_between_pitches_bases_empty(), pace.time_between_pitches_runners_on());
+	/// ```
+	#[must_use]
+	pub fn pace(&self) -> InGamePace {
+		let mut pace = InGamePace {
+			total_game_time: self.first().zip(self.last()).map_or_else(TimeDelta::zero, |(first, last)| last.play_end_timestamp - first.about.start_timestamp),
+			innings_played: innings_played(self),
+			runs: self.last().map_or(0, |play| play.result.home_score + play.result.away_score),
+			..InGamePace::default()
+		};
+		let mut pitchers = FxHashSet::default();
+		for (idx, play) in self.iter().enumerate() {
+			pitchers.extend(play.event_pitchers());
+			pace.record_play(play, idx.checked_sub(1).map(|previous| &self[previous]), self.get(idx + 1));
+		}
+		pace.num_pitchers = pitchers.len();
+		pace.inning_times = self.by_inning().filter_map(|mut inning| {
+			let first = inning.next()?;
+			let last = inning.last().unwrap_or(first);
+			Some((first.about.inning, last.play_end_timestamp - first.about.start_timestamp))
+		}).collect();
+		pace
+	}
+}
+
+impl InGamePace {
+	fn record_play(&mut self, play: &Play, previous: Option<&Play>, next: Option<&Play>) {
+		if let Some(details) = &play.result.completed_play_details && details.event.is_plate_appearance() {
+			self.plate_appearances += 1;
+			self.hits += usize::from(details.event.is_hit());
+			self.plate_appearance_times.push(play.play_end_timestamp - play.about.start_timestamp);
+		}
+		self.record_pitches(play, previous);
+		// dead time before the first event runs from the end of the previous play, unless that was the break between halves.
+		let play_start = previous.filter(|previous| previous.about.inning == play.about.inning && previous.about.inning_half == play.about.inning_half).map_or(play.about.start_timestamp, |previous| previous.play_end_timestamp);
+		for (idx, event) in play.play_events.iter().enumerate() {
+			if let Some(kind) = delay_kind(event) {
+				let before = idx.checked_sub(1).map_or(play_start, |previous| play.play_events[previous].end_timestamp);
+				let after = play.play_events.get(idx + 1).map_or(play.play_end_timestamp, |next| next.start_timestamp);
+				self.delays.push(PaceDelay { kind, at_bat_idx: play.about.at_bat_idx, play_event_idx: Some(idx), duration: Some(after - before) });
+			}
+		}
+		if !play.reviews.is_empty() {
+			let next = next.filter(|next| next.about.inning == play.about.inning && next.about.inning_half == play.about.inning_half);
+			let duration = next.map(|next| next.about.start_timestamp - play.play_end_timestamp);
+			self.delays.push(PaceDelay { kind: PaceDelayKind::Review, at_bat_idx: play.about.at_bat_idx, play_event_idx: None, duration });
+		}
+	}
+
+	fn record_pitches(&mut self, play: &Play, previous: Option<&Play>) {
+		let (bases, _) = play.starting_situation(previous);
+		let pitchers = play.event_pitchers();
+		let mut previous_pitch: Option<(&PlayEvent, _)> = None;
+		for (idx, (event, &pitcher)) in play.play_events.iter().zip(&pitchers).enumerate() {
+			if !matches!(event, PlayEvent::Pitch { .. }) {
+				continue;
+			}
+			self.num_pitches += 1;
+			if let Some((previous, _)) = previous_pitch.filter(|&(_, previous_pitcher)| previous_pitcher == pitcher) {
+				let interval = event.start_timestamp - previous.start_timestamp;
+				if play.bases_before(idx, bases).is_empty() {
+					self.pitch_intervals_bases_empty.push(interval);
+				} else {
+					self.pitch_intervals_runners_on.push(interval);
+				}
+			}
+			previous_pitch = Some((event, pitcher));
+		}
+	}
+}
+
+fn delay_kind(event: &PlayEvent) -> Option<PaceDelayKind> {
+	if !event.reviews.is_empty() {
+		return Some(PaceDelayKind::Review);
+	}
+	let PlayEvent::Action { details, .. } = event else { return None };
+	match details.event {
+		EventType::Injury => Some(PaceDelayKind::Injury),
+		EventType::PitchingSubstitution => Some(PaceDelayKind::PitchingChange),
+		_ => None,
+	}
+}
+
+#[allow(clippy::cast_precision_loss, reason = "a game has far fewer than 2^52 half-innings")]
+fn innings_played(plays: &Plays) -> f64 {
+	let halves = plays.by_inning_halves().map(|(mut top, mut bottom)| usize::from(top.next().is_some()) + usize::from(bottom.next().is_some())).sum::<usize>();
+	halves as f64 / 2.0
+}
+
+fn average(intervals: &[TimeDelta]) -> Option<TimeDelta> {
+	divide(intervals.iter().sum(), intervals.len())
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::TimeDelta;
+
+	use crate::game::{LiveFeedRequest, PaceDelayKind};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_pace() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let pace = feed.live.plays.pace();
+		let pitch_clock = feed.live.plays.pitch_clock();
+		assert_eq!(pace.runs, 9);
+		assert_eq!(pace.plate_appearances, pitch_clock.game.plate_appearances);
+		assert!((pace.innings_played - 11.0).abs() < f64::EPSILON);
+		assert_eq!(pace.inning_times.len(), 11);
+		assert_eq!(pitch_clock.game.pitches, pace.num_pitches);
+		let (bases_empty, runners_on) = (pace.time_between_pitches_bases_empty().unwrap(), pace.time_between_pitches_runners_on().unwrap());
+		assert!(TimeDelta::seconds(10) < bases_empty && bases_empty < runners_on && runners_on < TimeDelta::minutes(1), "{bases_empty} bases empty, {runners_on} runners on");
+		assert!(pace.delay(PaceDelayKind::PitchingChange) > TimeDelta::zero());
+		assert!(pace.delays.iter().filter(|delay| delay.kind == PaceDelayKind::PitchingChange).all(|delay| delay.duration.is_some_and(|duration| duration > TimeDelta::zero())));
+		assert!(pace.total_delay() <= pace.total_game_time);
+	}
+}
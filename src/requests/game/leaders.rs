@@ -0,0 +1,167 @@
+//! Game superlatives; the longest hit, hardest hit, and fastest pitch.
+
+use serde::Deserialize;
+use fxhash::FxHashMap;
+use serde_with::{serde_as, DisplayFromStr, PickFirst};
+use uom::si::f64::{Length, Velocity};
+use uom::si::length::foot;
+use uom::si::velocity::mile_per_hour;
+
+use crate::{game::{PlayEvent, Plays}, person::{NamedPerson, PersonId}};
+
+/// Number of leaders per category computed by [`GameStatLeaders::from_plays`], matches the default of [`StatLeadersRequest`](crate::stats::leaders::StatLeadersRequest).
+const LEADER_COUNT: usize = 5;
+
+/// Game records in stats like exit velocity, hit distance, etc.
+///
+/// The API usually leaves these empty, see [`GameStatLeaders::or_from_plays`].
+#[derive(Debug, Deserialize, PartialEq, Clone, Default)]
+#[serde(from = "__GameStatLeadersStruct")]
+pub struct GameStatLeaders {
+	/// Longest batted balls.
+	pub hit_distance: Vec<GameStatLeader<Length>>,
+	/// Hardest hit balls.
+	pub exit_velocity: Vec<GameStatLeader<Velocity>>,
+	/// Fastest pitches.
+	pub pitch_speed: Vec<GameStatLeader<Velocity>>,
+}
+
+/// A player's entry in a [`GameStatLeaders`] category.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct GameStatLeader<T> {
+	pub person: NamedPerson,
+	pub value: T,
+	/// Play the record was set on, `None` if provided by the API.
+	pub at_bat_idx: Option<usize>,
+}
+
+#[doc(hidden)]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
+struct __GameStatLeadersStruct {
+	#[serde(default)]
+	hit_distance: __GameStatLeaderboardStruct,
+	#[serde(default)]
+	hit_speed: __GameStatLeaderboardStruct,
+	#[serde(default)]
+	pitch_speed: __GameStatLeaderboardStruct,
+}
+
+#[doc(hidden)]
+#[derive(Deserialize, Default)]
+struct __GameStatLeaderboardStruct {
+	#[serde(default)]
+	leaders: Vec<__GameStatLeaderStruct>,
+}
+
+#[serde_as]
+#[doc(hidden)]
+#[derive(Deserialize)]
+struct __GameStatLeaderStruct {
+	#[serde(alias = "player")]
+	person: NamedPerson,
+	#[serde_as(as = "PickFirst<(_, DisplayFromStr)>")]
+	value: f64,
+}
+
+impl From<__GameStatLeadersStruct> for GameStatLeaders {
+	fn from(__GameStatLeadersStruct { hit_distance, hit_speed, pitch_speed }: __GameStatLeadersStruct) -> Self {
+		fn convert<T>(leaderboard: __GameStatLeaderboardStruct, unit: impl Fn(f64) -> T) -> Vec<GameStatLeader<T>> {
+			leaderboard.leaders.into_iter().map(|leader| GameStatLeader { person: leader.person, value: unit(leader.value), at_bat_idx: None }).collect()
+		}
+
+		Self {
+			hit_distance: convert(hit_distance, Length::new::<foot>),
+			exit_velocity: convert(hit_speed, Velocity::new::<mile_per_hour>),
+			pitch_speed: convert(pitch_speed, Velocity::new::<mile_per_hour>),
+		}
+	}
+}
+
+impl GameStatLeaders {
+	/// Computes the leaders from Statcast data in the `plays`; [`StatcastHitData::distance`](crate::game::StatcastHitData::distance), [`exit_velocity`](crate::game::StatcastHitData::exit_velocity) and [`PitchData::release_speed`](crate::game::PitchData::release_speed).
+	///
+	/// Each category has the top 5, a player can appear more than once.
+	#[must_use]
+	pub fn from_plays(plays: &Plays) -> Self {
+		// pitchers replaced mid at-bat aren't the matchup pitcher of the play, but are of an earlier one.
+		let pitchers = plays.iter().map(|play| (play.matchup.pitcher.id, &play.matchup.pitcher)).collect::<FxHashMap<PersonId, &NamedPerson>>();
+		let mut hit_distance = Vec::new();
+		let mut exit_velocity = Vec::new();
+		let mut pitch_speed = Vec::new();
+		for play in plays {
+			let at_bat_idx = Some(play.about.at_bat_idx);
+			for (event, pitcher) in play.play_events.iter().zip(play.event_pitchers()) {
+				let PlayEvent::Pitch { pitch_data, hit_data, .. } = event else { continue };
+				if let Some(pitch_data) = pitch_data && !pitch_data.release_speed.is_nan() {
+					let person = pitchers.get(&pitcher).map_or_else(|| NamedPerson { full_name: String::new(), id: pitcher }, |&person| person.clone());
+					pitch_speed.push(GameStatLeader { person, value: Velocity::new::<mile_per_hour>(pitch_data.release_speed), at_bat_idx });
+				}
+				if let Some(statcast) = hit_data.as_ref().and_then(|hit_data| hit_data.statcast.as_ref()) {
+					let person = &play.matchup.batter;
+					hit_distance.push(GameStatLeader { person: person.clone(), value: Length::new::<foot>(statcast.distance), at_bat_idx });
+					exit_velocity.push(GameStatLeader { person: person.clone(), value: Velocity::new::<mile_per_hour>(statcast.exit_velocity), at_bat_idx });
+				}
+			}
+		}
+		Self {
+			hit_distance: top(hit_distance),
+			exit_velocity: top(exit_velocity),
+			pitch_speed: top(pitch_speed),
+		}
+	}
+
+	/// Fills any categories the API left empty using [`Self::from_plays`].
+	#[must_use]
+	pub fn or_from_plays(self, plays: &Plays) -> Self {
+		if !self.hit_distance.is_empty() && !self.exit_velocity.is_empty() && !self.pitch_speed.is_empty() {
+			return self;
+		}
+		let computed = Self::from_plays(plays);
+		Self {
+			hit_distance: if self.hit_distance.is_empty() { computed.hit_distance } else { self.hit_distance },
+			exit_velocity: if self.exit_velocity.is_empty() { computed.exit_velocity } else { self.exit_velocity },
+			pitch_speed: if self.pitch_speed.is_empty() { computed.pitch_speed } else { self.pitch_speed },
+		}
+	}
+}
+
+/// The [`LEADER_COUNT`] largest values, largest first.
+fn top<T: PartialOrd>(mut leaders: Vec<GameStatLeader<T>>) -> Vec<GameStatLeader<T>> {
+	// NaN is the only value that isn't comparable to itself.
+	leaders.retain(|leader| leader.value.partial_cmp(&leader.value).is_some());
+	leaders.sort_by(|a, b| b.value.partial_cmp(&a.value).unwrap_or(std::cmp::Ordering::Equal));
+	leaders.truncate(LEADER_COUNT);
+	leaders
+}
+
+#[cfg(test)]
+mod tests {
+	use uom::si::velocity::mile_per_hour;
+
+	use crate::game::{GameStatLeaders, LiveFeedRequest};
+	use crate::request::RequestURLBuilderExt;
+
+	#[test]
+	fn empty_leaders() {
+		// as in current live feeds, ex: game 813024.
+		let leaders = serde_json::from_str::<GameStatLeaders>(r#"{ "hitDistance": {}, "hitSpeed": {}, "pitchSpeed": {} }"#).unwrap();
+		assert_eq!(leaders, GameStatLeaders::default());
+		assert!(serde_json::from_str::<GameStatLeaders>(r#"{ "hitDistance": 5, "hitSpeed": {}, "pitchSpeed": {} }"#).is_err());
+	}
+
+	#[tokio::test]
+	async fn ws_gm7_2025_leaders() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let leaders = feed.live.leaders.clone().or_from_plays(&feed.live.plays);
+		assert_ne!(leaders.hit_distance.len(), 0);
+		assert_ne!(leaders.exit_velocity.len(), 0);
+		assert_ne!(leaders.pitch_speed.len(), 0);
+		assert!(leaders.pitch_speed.windows(2).all(|pair| pair[0].value >= pair[1].value));
+		assert!(leaders.pitch_speed[0].value.get::<mile_per_hour>() > 90.0);
+		let computed = GameStatLeaders::from_plays(&feed.live.plays);
+		assert_eq!(computed.pitch_speed.len(), 5);
+		assert!(computed.pitch_speed.iter().all(|leader| !leader.person.full_name.is_empty()));
+	}
+}
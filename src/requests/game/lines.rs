@@ -0,0 +1,254 @@
+//! Pitching and batting lines reconstructed from play-by-play.
+//!
+//! Useful for partial-game lines (e.g. "through 6 innings") which the [`Boxscore`] cannot provide,
+//! and for auditing the official boxscore against the plays it's derived from.
+
+use fxhash::FxHashMap;
+
+use crate::{game::{Base, Boxscore, Play, PlayEvent, Plays}, meta::EventType, person::PersonId, stats::{CountingStat, InningsPitched}};
+
+/// A range of innings to accumulate plays over, inclusive on both ends.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct PlayWindow {
+	first: Option<usize>,
+	last: Option<usize>,
+}
+
+impl PlayWindow {
+	/// The whole game.
+	#[must_use]
+	pub const fn all() -> Self {
+		Self { first: None, last: None }
+	}
+
+	/// From the start of the game through the end of `inning`; "through 6".
+	#[must_use]
+	pub const fn through(inning: usize) -> Self {
+		Self { first: None, last: Some(inning) }
+	}
+
+	/// From the start of `inning` onwards; "since the 7th".
+	#[must_use]
+	pub const fn since(inning: usize) -> Self {
+		Self { first: Some(inning), last: None }
+	}
+
+	/// From the start of `first` through the end of `last`.
+	#[must_use]
+	pub const fn innings(first: usize, last: usize) -> Self {
+		Self { first: Some(first), last: Some(last) }
+	}
+
+	/// Whether the play falls within this window.
+	#[must_use]
+	pub fn contains(&self, play: &Play) -> bool {
+		let inning = *play.about.inning;
+		self.first.is_none_or(|first| inning >= first) && self.last.is_none_or(|last| inning <= last)
+	}
+}
+
+/// A pitcher's line, as would be shown in a boxscore.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct PitchingLine {
+	pub outs: CountingStat,
+	pub hits: CountingStat,
+	pub runs: CountingStat,
+	pub earned_runs: CountingStat,
+	pub base_on_balls: CountingStat,
+	pub strikeouts: CountingStat,
+	pub home_runs: CountingStat,
+	pub hit_by_pitch: CountingStat,
+	pub batters_faced: CountingStat,
+	/// Pitches thrown, including those put in play.
+	pub pitches: CountingStat,
+	/// Strikes thrown, including those put in play.
+	pub strikes: CountingStat,
+	pub balls: CountingStat,
+}
+
+impl PitchingLine {
+	#[must_use]
+	pub const fn innings_pitched(&self) -> InningsPitched {
+		InningsPitched::from_outs(self.outs)
+	}
+}
+
+/// A batter's line, as would be shown in a boxscore.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct BattingLine {
+	pub plate_appearances: CountingStat,
+	pub at_bats: CountingStat,
+	pub runs: CountingStat,
+	pub hits: CountingStat,
+	pub doubles: CountingStat,
+	pub triples: CountingStat,
+	pub home_runs: CountingStat,
+	pub rbi: CountingStat,
+	pub base_on_balls: CountingStat,
+	pub strikeouts: CountingStat,
+	pub hit_by_pitch: CountingStat,
+	pub sac_bunts: CountingStat,
+	pub sac_flies: CountingStat,
+}
+
+/// Every player's pitching and batting line over a [`PlayWindow`].
+///
+/// Players who did not pitch (or bat) within the window are absent rather than zeroed.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct GameLines {
+	pub pitching: FxHashMap<PersonId, PitchingLine>,
+	pub batting: FxHashMap<PersonId, BattingLine>,
+}
+
+/// A stat where the reconstructed line and the official [`Boxscore`] disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineDiscrepancy {
+	pub player: PersonId,
+	/// Field name on [`PitchingLine`] or [`BattingLine`]
+	pub stat: &'static str,
+	pub reconstructed: CountingStat,
+	pub official: CountingStat,
+}
+
+impl Plays {
+	/// Reconstructs every player's pitching and batting line from the plays within `window`.
+	///
+	/// Pitches and outs are credited to whoever was on the mound when they happened; plate appearance results go to the pitcher who finished the at-bat.
+	/// Runs are charged to the [responsible pitcher](crate::game::RunnerDetails::responsible_pitcher), so inherited runners count against the pitcher who put them on.
+	///
+	/// ## Examples
+	/// ```no_run
+	/// let plays: Plays = ...;
+	///
+	/// let through_six = plays.lines(PlayWindow::through(6));
+	/// for (pitcher, line) in &through_six.pitching {
+	///     println!("{pitcher}: {} IP, {} ER", line.innings_pitched(), line.earned_runs);
+	/// }
+	/// ```
+	#[must_use]
+	pub fn lines(&self, window: PlayWindow) -> GameLines {
+		let mut lines = GameLines::default();
+		for play in self.iter().filter(|play| window.contains(play)) {
+			lines.record_pitches(play);
+			lines.record_plate_appearance(play);
+			lines.record_runners(play);
+		}
+		lines
+	}
+}
+
+impl GameLines {
+	fn record_pitches(&mut self, play: &Play) {
+		for (event, pitcher) in play.play_events.iter().zip(play.event_pitchers()) {
+			if let PlayEvent::Pitch { details, .. } = event {
+				let line = self.pitching.entry(pitcher).or_default();
+				line.pitches += 1;
+				line.strikes += CountingStat::from(details.is_strike || details.is_in_play);
+				line.balls += CountingStat::from(details.is_ball);
+			}
+		}
+	}
+
+	fn record_plate_appearance(&mut self, play: &Play) {
+		let Some(completed) = &play.result.completed_play_details else { return };
+		let event = completed.event;
+		if !event.is_plate_appearance() {
+			return;
+		}
+
+		let pitching = self.pitching.entry(play.matchup.pitcher.id).or_default();
+		pitching.batters_faced += 1;
+		pitching.hits += CountingStat::from(event.is_hit());
+		pitching.home_runs += CountingStat::from(event == EventType::HomeRun);
+		pitching.base_on_balls += CountingStat::from(event.is_walk());
+		pitching.strikeouts += CountingStat::from(event.is_strikeout());
+		pitching.hit_by_pitch += CountingStat::from(event == EventType::HitByPitch);
+
+		let batting = self.batting.entry(play.matchup.batter.id).or_default();
+		batting.plate_appearances += 1;
+		batting.at_bats += CountingStat::from(event.is_at_bat());
+		batting.hits += CountingStat::from(event.is_hit());
+		batting.doubles += CountingStat::from(event == EventType::Double);
+		batting.triples += CountingStat::from(event == EventType::Triple);
+		batting.home_runs += CountingStat::from(event == EventType::HomeRun);
+		batting.rbi += CountingStat::try_from(completed.rbi).unwrap_or(CountingStat::MAX);
+		batting.base_on_balls += CountingStat::from(event.is_walk());
+		batting.strikeouts += CountingStat::from(event.is_strikeout());
+		batting.hit_by_pitch += CountingStat::from(event == EventType::HitByPitch);
+		batting.sac_bunts += CountingStat::from(matches!(event, EventType::SacrificeBunt | EventType::SacrificeBuntDoublePlay));
+		batting.sac_flies += CountingStat::from(matches!(event, EventType::SacrificeFly | EventType::SacrificeFlyDoublePlay));
+	}
+
+	fn record_runners(&mut self, play: &Play) {
+		let pitchers = play.event_pitchers();
+		for runner in &play.runners {
+			// the pitcher on the mound when the runner moved, so outs before a mid at-bat pitching change go to the replaced pitcher.
+			let pitcher = runner.details.play_event_index.and_then(|idx| pitchers.get(idx)).copied().unwrap_or(play.matchup.pitcher.id);
+			if runner.movement.is_out {
+				self.pitching.entry(pitcher).or_default().outs += 1;
+			} else if runner.movement.end_base == Some(Base::Home) {
+				let pitching = self.pitching.entry(runner.details.responsible_pitcher.unwrap_or(pitcher)).or_default();
+				pitching.runs += 1;
+				pitching.earned_runs += CountingStat::from(runner.details.is_earned);
+				self.batting.entry(runner.details.runner.id).or_default().runs += 1;
+			}
+		}
+	}
+
+	/// Compares these lines against the official game stats in `boxscore`.
+	///
+	/// Only meaningful for [`PlayWindow::all`]; stats omitted from the boxscore are skipped.
+	#[must_use]
+	pub fn cross_check(&self, boxscore: &Boxscore) -> Vec<LineDiscrepancy> {
+		macro_rules! compare {
+			($discrepancies:ident, $player:expr, $line:expr, $official:expr, { $($field:ident => $official_field:ident),* $(,)? }) => {
+				$(
+				if let Ok(official) = $official.$official_field && official != $line.$field {
+					$discrepancies.push(LineDiscrepancy { player: $player, stat: stringify!($field), reconstructed: $line.$field, official });
+				}
+				)*
+			};
+		}
+
+		let mut discrepancies = Vec::new();
+		for (&player, line) in &self.pitching {
+			let Some(official) = boxscore.find_player_with_game_data(player) else { continue };
+			let official = &official.game_stats.pitching;
+			compare!(discrepancies, player, line, official, {
+				outs => outs, hits => hits, runs => runs, earned_runs => earned_runs, base_on_balls => base_on_balls, strikeouts => strikeouts, home_runs => home_runs,
+				hit_by_pitch => hit_by_pitch, batters_faced => batters_faced, pitches => number_of_pitches, strikes => strikes, balls => balls,
+			});
+		}
+		for (&player, line) in &self.batting {
+			let Some(official) = boxscore.find_player_with_game_data(player) else { continue };
+			let official = &official.game_stats.hitting;
+			compare!(discrepancies, player, line, official, {
+				plate_appearances => plate_appearances, at_bats => at_bats, runs => runs, hits => hits, doubles => doubles, triples => triples, home_runs => home_runs,
+				rbi => rbi, base_on_balls => base_on_balls, strikeouts => strikeouts, hit_by_pitch => hit_by_pitch, sac_bunts => sac_bunts, sac_flies => sac_flies,
+			});
+		}
+		discrepancies
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{LiveFeedRequest, PlayWindow};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_lines() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let plays = &feed.live.plays;
+		let lines = plays.lines(PlayWindow::all());
+		let discrepancies = lines.cross_check(&feed.live.boxscore);
+		assert!(discrepancies.is_empty(), "{discrepancies:#?}");
+
+		let runs = feed.live.linescore.rhe_totals.as_ref().map(|rhe| rhe.runs).added();
+		assert_eq!(lines.batting.values().map(|line| line.runs as usize).sum::<usize>(), runs);
+		assert_eq!(lines.pitching.values().map(|line| line.runs as usize).sum::<usize>(), runs);
+
+		let through_six = plays.lines(PlayWindow::through(6));
+		assert!(through_six.pitching.values().map(|line| line.outs).sum::<u32>() <= 36);
+	}
+}
@@ -8,8 +8,9 @@ use serde_with::{serde_as, DefaultOnError};
 
 use crate::request::RequestURL;
 use crate::{Copyright, HomeAway};
-use crate::game::{AtBatCount, GameId, Inning, InningHalf, RHE};
-use crate::person::NamedPerson;
+use crate::game::{AtBatCount, Base, BaseOccupancy, GameId, Inning, InningHalf, RHE};
+use crate::meta::FieldingPosition;
+use crate::person::{NamedPerson, PersonId};
 use crate::team::NamedTeam;
 
 /// An inning by inning record of the game's scoring.
@@ -84,21 +85,23 @@ pub struct LinescoreOffense {
     #[serde(rename = "battingOrder")]
     pub batting_order_index: usize,
 
-    #[doc(hidden)]
-    #[serde(rename = "pitcher", default)]
-    pub __pitcher: IgnoredAny,
-    #[doc(hidden)]
-    #[serde(rename = "catcher", default)]
-    pub __catcher: IgnoredAny,
-    #[doc(hidden)]
+    /// The batting team's pitcher, who'll be on the mound next half-inning.
+    #[serde(default)]
+    pub pitcher: Option<NamedPerson>,
+    /// Runner on first base, if any.
     #[serde(rename = "first", default)]
-    pub __first_baseman: IgnoredAny,
-    #[doc(hidden)]
+    pub runner_on_first: Option<NamedPerson>,
+    /// Runner on second base, if any.
     #[serde(rename = "second", default)]
-    pub __second_baseman: IgnoredAny,
-    #[doc(hidden)]
+    pub runner_on_second: Option<NamedPerson>,
+    /// Runner on third base, if any.
     #[serde(rename = "third", default)]
-    pub __third_baseman: IgnoredAny,
+    pub runner_on_third: Option<NamedPerson>,
+
+    // the batting team's fielders, missing their infielders since those keys are the runners.
+    #[doc(hidden)]
+    #[serde(rename = "catcher", default)]
+    pub __catcher: IgnoredAny,
     #[doc(hidden)]
     #[serde(rename = "shortstop", default)]
     pub __shortstop: IgnoredAny,
@@ -113,10 +116,67 @@ pub struct LinescoreOffense {
     pub __rightfielder: IgnoredAny,
 }
 
-/// Current defense in the linescore, note that it also contains their offense too.
-#[derive(Debug, Deserialize, PartialEq, Clone, Deref, DerefMut)]
+impl LinescoreOffense {
+    /// Runner on `base`, if any; never anyone on [`Base::Home`].
+    #[must_use]
+    pub const fn runner_on(&self, base: Base) -> Option<&NamedPerson> {
+        match base {
+            Base::First => self.runner_on_first.as_ref(),
+            Base::Second => self.runner_on_second.as_ref(),
+            Base::Third => self.runner_on_third.as_ref(),
+            Base::Home => None,
+        }
+    }
+
+    /// Runners on base, lead runner last.
+    pub fn runners(&self) -> impl Iterator<Item = (Base, &NamedPerson)> {
+        [Base::First, Base::Second, Base::Third].into_iter().filter_map(|base| self.runner_on(base).map(|runner| (base, runner)))
+    }
+
+    #[must_use]
+    pub const fn bases(&self) -> BaseOccupancy {
+        BaseOccupancy {
+            first: self.runner_on_first.is_some(),
+            second: self.runner_on_second.is_some(),
+            third: self.runner_on_third.is_some(),
+        }
+    }
+}
+
+/// Current defense in the linescore, note that it also contains their upcoming batters too.
+///
+/// [`Deref`]s to the [`DefensiveAlignment`], so `defense.pitcher` is the pitcher on the mound.
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone, Deref, DerefMut)]
 #[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
 pub struct LinescoreDefense {
+    #[deref]
+    #[deref_mut]
+    #[serde(flatten)]
+    pub alignment: DefensiveAlignment,
+
+    #[serde(flatten)]
+    pub upcoming: UpcomingBatters,
+}
+
+/// The fielding team's upcoming batters once they come up to bat, see [`LinescoreDefense`].
+///
+/// Unlike [`LinescoreOffense`], there are no runners here; the `first`, `second` and `third` keys are the fielders.
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UpcomingBatters {
+    pub batter: NamedPerson,
+    pub on_deck: NamedPerson,
+    #[serde(rename = "inHole")]
+    pub in_the_hole: NamedPerson,
+    pub team: NamedTeam,
+    /// Index of the current player in the batting order
+    #[serde(rename = "battingOrder")]
+    pub batting_order_index: usize,
+}
+
+/// Who's playing where for the team in the field.
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+pub struct DefensiveAlignment {
     pub pitcher: NamedPerson,
     pub catcher: NamedPerson,
     #[serde(rename = "first")]
@@ -132,11 +192,35 @@ pub struct LinescoreDefense {
     pub centerfielder: NamedPerson,
     #[serde(rename = "right")]
     pub rightfielder: NamedPerson,
-    
-    #[deref]
-    #[deref_mut]
-    #[serde(flatten)]
-    pub offense: LinescoreOffense,
+}
+
+impl DefensiveAlignment {
+    /// The fielder at `position`, see [`NamedPosition::fielding_position`](crate::meta::NamedPosition::fielding_position) for a [`Position`](crate::meta::Position).
+    #[must_use]
+    pub const fn get(&self, position: FieldingPosition) -> &NamedPerson {
+        match position {
+            FieldingPosition::Pitcher => &self.pitcher,
+            FieldingPosition::Catcher => &self.catcher,
+            FieldingPosition::FirstBase => &self.first_baseman,
+            FieldingPosition::SecondBase => &self.second_baseman,
+            FieldingPosition::ThirdBase => &self.third_baseman,
+            FieldingPosition::Shortstop => &self.shortstop,
+            FieldingPosition::LeftField => &self.leftfielder,
+            FieldingPosition::CenterField => &self.centerfielder,
+            FieldingPosition::RightField => &self.rightfielder,
+        }
+    }
+
+    /// Every fielder with their position, pitcher through right field.
+    pub fn iter(&self) -> impl Iterator<Item = (FieldingPosition, &NamedPerson)> {
+        FieldingPosition::ALL.into_iter().map(|position| (position, self.get(position)))
+    }
+
+    /// Position the player is fielding, if they're in the field.
+    #[must_use]
+    pub fn position_of(&self, person: PersonId) -> Option<FieldingPosition> {
+        self.iter().find(|(_, fielder)| fielder.id == person).map(|(position, _)| position)
+    }
 }
 
 /// Returns a [`Linescore`]
@@ -165,7 +249,7 @@ impl RequestURL for LinescoreRequest {
 mod tests {
     use crate::TEST_YEAR;
     use crate::game::LinescoreRequest;
-    use crate::meta::GameType;
+    use crate::meta::{FieldingPosition, GameType};
     use crate::request::RequestURLBuilderExt;
     use crate::schedule::ScheduleRequest;
     use crate::season::{Season, SeasonsRequest};
@@ -173,7 +257,16 @@ mod tests {
 
     #[tokio::test]
     async fn ws_gm7_2025_linescore() {
-        let _ = LinescoreRequest::builder().id(813_024).build_and_get().await.unwrap();
+        let linescore = LinescoreRequest::builder().id(813_024).build_and_get().await.unwrap();
+        let defense = linescore.defense.expect("Expected a defense");
+        assert_eq!(defense.pitcher, defense.alignment.pitcher);
+        assert_ne!(defense.upcoming.team.id, linescore.offense.expect("Expected an offense").team.id);
+        let alignment = &defense.alignment;
+        for (position, fielder) in alignment.iter() {
+            assert_eq!(alignment.get(position), fielder);
+            assert_eq!(FieldingPosition::from_code(&position.code()), Some(position));
+            assert_eq!(alignment.position_of(fielder.id), Some(position));
+        }
     }
     
     #[tokio::test]
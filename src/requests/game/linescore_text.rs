@@ -0,0 +1,236 @@
+//! Scoreboard-style rendering of a [`Linescore`].
+//!
+//! ```text
+//!     | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 || R |  H | E |
+//! LAD | 0 | 0 | 0 | 1 | 0 | 1 | 0 | 1 | 1 |  0 |  1 || 5 | 11 | 0 |
+//! TOR | 0 | 0 | 3 | 0 | 0 | 1 | 0 | 0 | 0 |  0 |  0 || 4 | 14 | 0 |
+//! ```
+
+use std::fmt::{Display, Formatter};
+
+use crate::{HomeAway, TeamSide, game::{Inning, InningHalf, Linescore, LinescoreInningRecord}, team::TeamName};
+
+/// Table style for [`Linescore::table`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub enum LinescoreStyle {
+	/// `|` separated columns, see [`self`].
+	#[default]
+	Ascii,
+	/// Box-drawing characters, with a double line before the R/H/E totals.
+	Unicode,
+	/// A Markdown table.
+	Markdown,
+}
+
+/// A [`Display`]able linescore, see [`Linescore::table`].
+#[derive(Debug, Copy, Clone)]
+pub struct LinescoreTable<'a> {
+	linescore: &'a Linescore,
+	abbreviations: HomeAway<&'a str>,
+	style: LinescoreStyle,
+	situation: bool,
+}
+
+impl Linescore {
+	/// Renders this linescore as an inning-by-inning table with R/H/E totals.
+	///
+	/// Innings past those scheduled are added as they're played, halves yet to be played are left blank, and the unneeded bottom half of a home win is an `X`.
+	///
+	/// ## Examples
+	/// ```no_run
+	/// let feed: LiveFeedResponse = ...;
+	///
+	/// let teams = feed.data.teams.as_ref().map(|team| &team.name);
+	/// println!("{}", feed.live.linescore.table(teams, LinescoreStyle::Unicode).with_situation());
+	/// ```
+	#[must_use]
+	pub fn table<'a>(&'a self, teams: HomeAway<&'a TeamName>, style: LinescoreStyle) -> LinescoreTable<'a> {
+		LinescoreTable {
+			linescore: self,
+			abbreviations: teams.map(|name| name.abbreviation.as_str()),
+			style,
+			situation: false,
+		}
+	}
+
+	/// Whether `side`'s half of `inning` went unplayed because the game was already decided, the "X" in a linescore.
+	#[must_use]
+	pub fn was_inning_half_skipped(&self, inning: Inning, side: TeamSide) -> bool {
+		let Some(record) = self.innings.iter().find(|record| record.inning == inning) else { return false };
+		let is_last_inning = self.innings.last().is_some_and(|last| last.inning == inning);
+		let is_top_over = self.inning_half == InningHalf::Bottom || *self.current_inning > *inning || self.count.outs >= 3;
+		side == TeamSide::Home
+			&& !record.inning_record.home.was_inning_half_played
+			&& *inning >= self.scheduled_innings
+			&& is_last_inning
+			&& is_top_over
+			&& self.rhe_totals.home.runs > self.rhe_totals.away.runs
+	}
+}
+
+impl LinescoreTable<'_> {
+	/// Adds a line above the table with the inning, count, outs, batter and base runners.
+	#[must_use]
+	pub const fn with_situation(mut self) -> Self {
+		self.situation = true;
+		self
+	}
+
+	/// Header row followed by the away and home rows.
+	fn rows(&self) -> [Vec<String>; 3] {
+		let linescore = self.linescore;
+		let innings = linescore.scheduled_innings.max(linescore.innings.len()).max(*linescore.current_inning);
+		let header = std::iter::once(String::new())
+			.chain((1..=innings).map(|inning| inning.to_string()))
+			.chain(["R", "H", "E"].map(str::to_owned))
+			.collect();
+		let row = |side: TeamSide| {
+			let totals = linescore.rhe_totals.as_ref().choose(side);
+			std::iter::once(self.abbreviations.choose(side).to_owned())
+				.chain((1..=innings).map(|inning| self.inning_cell(linescore.innings.iter().find(|record| *record.inning == inning), side)))
+				.chain([totals.runs, totals.hits, totals.errors].map(|value| value.to_string()))
+				.collect()
+		};
+		[header, row(TeamSide::Away), row(TeamSide::Home)]
+	}
+
+	fn inning_cell(&self, record: Option<&LinescoreInningRecord>, side: TeamSide) -> String {
+		let Some(record) = record else { return String::new() };
+		let rhe = record.inning_record.as_ref().choose(side);
+		if rhe.was_inning_half_played {
+			rhe.runs.to_string()
+		} else if self.linescore.was_inning_half_skipped(record.inning, side) {
+			"X".to_owned()
+		} else {
+			String::new()
+		}
+	}
+
+	fn write_situation(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let linescore = self.linescore;
+		let half = match self.style {
+			LinescoreStyle::Unicode => linescore.inning_half.unicode_char_filled().to_string(),
+			LinescoreStyle::Ascii | LinescoreStyle::Markdown => linescore.inning_half.three_char().to_owned(),
+		};
+		write!(f, "{half} {}, {}", linescore.current_inning, linescore.count)?;
+		if let Some(offense) = &linescore.offense {
+			let bases = offense.runners().map(|(base, _)| base.to_string()).collect::<Vec<_>>();
+			let bases = if bases.is_empty() { "bases empty".to_owned() } else { format!("on {}", bases.join(", ")) };
+			write!(f, ", {bases}; {} batting", offense.batter.full_name)?;
+		}
+		writeln!(f)?;
+		if self.style == LinescoreStyle::Markdown {
+			writeln!(f)?;
+		}
+		Ok(())
+	}
+}
+
+impl Display for LinescoreTable<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		if self.situation {
+			self.write_situation(f)?;
+		}
+		let rows = self.rows();
+		let widths = (0..rows[0].len()).map(|column| rows.iter().map(|row| row[column].chars().count()).max().unwrap_or(0).max(1)).collect::<Vec<_>>();
+		// first column index of the R/H/E totals
+		let totals = widths.len() - 3;
+		match self.style {
+			LinescoreStyle::Ascii => write_ascii(f, &rows, &widths, totals),
+			LinescoreStyle::Unicode => write_unicode(f, &rows, &widths, totals),
+			LinescoreStyle::Markdown => write_markdown(f, &rows, &widths),
+		}
+	}
+}
+
+fn write_ascii(f: &mut Formatter<'_>, rows: &[Vec<String>; 3], widths: &[usize], totals: usize) -> std::fmt::Result {
+	for row in rows {
+		for (column, (cell, &width)) in row.iter().zip(widths).enumerate() {
+			match column {
+				0 => write!(f, "{cell:<width$} |")?,
+				_ if column == totals => write!(f, "| {cell:>width$} |")?,
+				_ => write!(f, " {cell:>width$} |")?,
+			}
+		}
+		writeln!(f)?;
+	}
+	Ok(())
+}
+
+fn write_unicode(f: &mut Formatter<'_>, rows: &[Vec<String>; 3], widths: &[usize], totals: usize) -> std::fmt::Result {
+	let border = |f: &mut Formatter<'_>, [left, middle, double, right]: [char; 4]| -> std::fmt::Result {
+		write!(f, "{left}")?;
+		for (column, &width) in widths.iter().enumerate() {
+			if column > 0 {
+				write!(f, "{}", if column == totals { double } else { middle })?;
+			}
+			write!(f, "{}", "─".repeat(width + 2))?;
+		}
+		writeln!(f, "{right}")
+	};
+
+	border(f, ['┌', '┬', '╥', '┐'])?;
+	for (idx, row) in rows.iter().enumerate() {
+		if idx == 1 {
+			border(f, ['├', '┼', '╫', '┤'])?;
+		}
+		for (column, (cell, &width)) in row.iter().zip(widths).enumerate() {
+			let separator = if column == totals { '║' } else { '│' };
+			if column == 0 {
+				write!(f, "{separator} {cell:<width$} ")?;
+			} else {
+				write!(f, "{separator} {cell:>width$} ")?;
+			}
+		}
+		writeln!(f, "│")?;
+	}
+	border(f, ['└', '┴', '╨', '┘'])
+}
+
+fn write_markdown(f: &mut Formatter<'_>, rows: &[Vec<String>; 3], widths: &[usize]) -> std::fmt::Result {
+	for (idx, row) in rows.iter().enumerate() {
+		if idx == 1 {
+			for (column, &width) in widths.iter().enumerate() {
+				let dashes = "-".repeat(width.saturating_sub(1).max(1));
+				if column == 0 {
+					write!(f, "| :{dashes} ")?;
+				} else {
+					write!(f, "| {dashes}: ")?;
+				}
+			}
+			writeln!(f, "|")?;
+		}
+		for (column, (cell, &width)) in row.iter().zip(widths).enumerate() {
+			if column == 0 {
+				write!(f, "| {cell:<width$} ")?;
+			} else {
+				write!(f, "| {cell:>width$} ")?;
+			}
+		}
+		writeln!(f, "|")?;
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{LinescoreStyle, LiveFeedRequest};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_linescore_table() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let teams = feed.data.teams.as_ref().map(|team| &team.name);
+		for style in [LinescoreStyle::Ascii, LinescoreStyle::Unicode, LinescoreStyle::Markdown] {
+			let table = feed.live.linescore.table(teams, style).with_situation().to_string();
+			assert!(table.contains(&teams.away.abbreviation));
+			assert!(table.contains(&teams.home.abbreviation));
+			assert!(table.contains("11"), "extra innings column is missing");
+		}
+		let table = feed.live.linescore.table(teams, LinescoreStyle::Ascii).to_string();
+		let mut lines = table.lines();
+		assert!(lines.next().unwrap().starts_with("    | 1 | 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 | 10 | 11 || R |"));
+		assert!(lines.next().unwrap().starts_with("LAD | 0 | 0 | 0 | 1 | 0 | 1 | 0 | 1 | 1 |  0 |  1 || 5 |"));
+		assert!(lines.next().unwrap().starts_with("TOR | 0 | 0 | 3 | 0 | 0 | 1 | 0 | 0 | 0 |  0 |  0 || 4 |"));
+	}
+}
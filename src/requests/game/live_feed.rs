@@ -18,6 +18,7 @@ use crate::{Copyright, HomeAway};
 use crate::venue::{Venue, VenueId};
 
 /// See [`self`]
+#[allow(clippy::unsafe_derive_deserialize, reason = "not relevant here")]
 #[derive(Debug, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
@@ -24,28 +24,64 @@ use crate::{DayHalf, HomeAway, ResourceUsage, ResultHoldingResourceUsage, TeamSi
 use crate::meta::WindDirectionId;
 use crate::request;
 
+mod batted_ball;
 mod boxscore; // done
+mod boxscore_text;
+mod challenges;
 mod changes;
 mod content;
 mod context_metrics;
+mod delta;
 mod diff;
+mod in_game_pace;
+mod leaders;
+mod lines;
 mod linescore; // done
+mod linescore_text;
 mod pace; // done
+mod pitch_clock;
 mod plays; // done
+mod query;
+mod recap;
+mod related;
+mod retrosheet;
+mod run_expectancy;
+mod scorecard;
 mod timestamps; // done
+mod trajectory;
+mod umpire_scorecard;
+mod win_expectancy;
 mod uniforms;
 mod win_probability;
 mod live_feed; // done
 
+pub use batted_ball::*;
 pub use boxscore::*;
+pub use boxscore_text::*;
+pub use challenges::*;
 pub use changes::*;
 pub use content::*;
 pub use context_metrics::*;
+pub use delta::*;
 pub use diff::*;
+pub use in_game_pace::*;
+pub use leaders::*;
+pub use lines::*;
 pub use linescore::*;
+pub use linescore_text::*;
 pub use pace::*;
+pub use pitch_clock::*;
 pub use plays::*;
+pub use query::*;
+pub use recap::*;
+pub use related::*;
+pub use retrosheet::*;
+pub use run_expectancy::*;
+pub use scorecard::*;
 pub use timestamps::*;
+pub use trajectory::*;
+pub use umpire_scorecard::*;
+pub use win_expectancy::*;
 pub use uniforms::*;
 pub use win_probability::*;
 pub use live_feed::*;
@@ -97,9 +133,181 @@ pub struct GameResumedDateTime {
 #[derive(Debug, Deserialize, PartialEq, Clone, Default)]
 #[serde(try_from = "__WeatherConditionsStruct")]
 pub struct WeatherConditions {
-	pub condition: Option<String>,
+	pub condition: Option<SkyCondition>,
 	pub temp: Option<uom::si::f64::ThermodynamicTemperature>,
-	pub wind: Option<(uom::si::f64::Velocity, WindDirectionId)>,
+	pub wind: Option<Wind>,
+}
+
+impl WeatherConditions {
+	/// Parses the `Weather` and `Wind` entries of the [`Boxscore`] misc info, ex: `"68 degrees, Roof Closed."` and `"5 mph, Out To CF."`.
+	///
+	/// # Errors
+	/// If the wind is present but not in a known format.
+	pub fn from_labelled_values(values: &[LabelledValue]) -> Result<Self, &'static str> {
+		let value = |label: &str| values.iter().find(|value| value.label == label).map(|value| value.value.trim().trim_end_matches('.'));
+		let (temp, condition) = value("Weather").map_or((None, None), |weather| match weather.split_once(", ") {
+			Some((temp, condition)) => (temp.strip_suffix(" degrees"), Some(condition)),
+			None => (weather.strip_suffix(" degrees"), None),
+		});
+		Ok(Self {
+			condition: condition.map(SkyCondition::from),
+			temp: temp.and_then(parse_fahrenheit),
+			wind: value("Wind").map(str::parse).transpose()?,
+		})
+	}
+
+	/// Whether the roof was open or closed, given the venue's [`RoofType`](crate::RoofType) (see [`FieldInfo`](crate::FieldInfo)).
+	#[must_use]
+	pub const fn roof_state(&self, roof_type: &crate::RoofType) -> RoofState {
+		RoofState::new(roof_type, self.condition.as_ref())
+	}
+}
+
+fn parse_fahrenheit(temp: &str) -> Option<uom::si::f64::ThermodynamicTemperature> {
+	temp.trim().parse::<i32>().ok().map(|temp| uom::si::f64::ThermodynamicTemperature::new::<uom::si::thermodynamic_temperature::degree_fahrenheit>(f64::from(temp)))
+}
+
+/// Conditions of the sky, see [`SkyDescription`](crate::meta::SkyDescription) for the full list.
+#[derive(Debug, PartialEq, Eq, Clone, Display)]
+pub enum SkyCondition {
+	#[display("Clear")]
+	Clear,
+	#[display("Sunny")]
+	Sunny,
+	#[display("Partly Cloudy")]
+	PartlyCloudy,
+	#[display("Cloudy")]
+	Cloudy,
+	#[display("Overcast")]
+	Overcast,
+	#[display("Drizzle")]
+	Drizzle,
+	#[display("Rain")]
+	Rain,
+	#[display("Snow")]
+	Snow,
+	/// The venue is a dome.
+	#[display("Dome")]
+	Dome,
+	/// The venue's retractable roof is closed.
+	#[display("Roof Closed")]
+	RoofClosed,
+	/// A condition not listed above.
+	#[display("{_0}")]
+	Unknown(String),
+}
+
+impl From<&str> for SkyCondition {
+	fn from(value: &str) -> Self {
+		match value.trim() {
+			"Clear" => Self::Clear,
+			"Sunny" => Self::Sunny,
+			"Partly Cloudy" => Self::PartlyCloudy,
+			"Cloudy" => Self::Cloudy,
+			"Overcast" => Self::Overcast,
+			"Drizzle" => Self::Drizzle,
+			"Rain" => Self::Rain,
+			"Snow" => Self::Snow,
+			"Dome" => Self::Dome,
+			"Roof Closed" => Self::RoofClosed,
+			other => Self::Unknown(other.to_owned()),
+		}
+	}
+}
+
+impl SkyCondition {
+	/// Whether the field is covered from the sky.
+	#[must_use]
+	pub const fn is_covered(&self) -> bool {
+		matches!(self, Self::Dome | Self::RoofClosed)
+	}
+
+	/// Whether it's precipitating.
+	#[must_use]
+	pub const fn is_precipitation(&self) -> bool {
+		matches!(self, Self::Drizzle | Self::Rain | Self::Snow)
+	}
+
+	/// Identifier of the corresponding [`SkyDescription`](crate::meta::SkyDescription).
+	#[must_use]
+	pub fn sky_description_id(&self) -> crate::meta::SkyDescriptionId {
+		crate::meta::SkyDescriptionId::new(self.to_string())
+	}
+}
+
+/// The state of a venue's roof during a game.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Display)]
+pub enum RoofState {
+	/// The venue has no roof.
+	#[display("Open Air")]
+	OpenAir,
+	/// A retractable roof that's open.
+	#[display("Open")]
+	Open,
+	/// A retractable roof that's closed.
+	#[display("Closed")]
+	Closed,
+	#[display("Dome")]
+	Dome,
+}
+
+impl RoofState {
+	/// Joins the venue's [`RoofType`](crate::RoofType) with the reported sky condition; retractable roofs are considered open unless reported otherwise.
+	#[must_use]
+	pub const fn new(roof_type: &crate::RoofType, condition: Option<&SkyCondition>) -> Self {
+		match roof_type {
+			crate::RoofType::Open => Self::OpenAir,
+			crate::RoofType::Dome => Self::Dome,
+			crate::RoofType::Retractable => match condition {
+				Some(condition) if condition.is_covered() => Self::Closed,
+				_ => Self::Open,
+			},
+		}
+	}
+
+	/// Whether the weather affects play.
+	#[must_use]
+	pub const fn is_exposed(self) -> bool {
+		matches!(self, Self::OpenAir | Self::Open)
+	}
+}
+
+/// Wind speed and direction relative to the field.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Wind {
+	/// `None` when no speed is reported, such as for `"Varies"`.
+	pub speed: Option<uom::si::f64::Velocity>,
+	pub direction: WindDirectionId,
+}
+
+impl Wind {
+	/// No wind at all.
+	#[must_use]
+	pub fn is_calm(&self) -> bool {
+		self.speed.is_some_and(|speed| speed.get::<uom::si::velocity::mile_per_hour>() <= 0.0) || self.direction.eq_ignore_ascii_case("calm")
+	}
+
+	/// Wind without a consistent direction.
+	#[must_use]
+	pub fn varies(&self) -> bool {
+		self.direction.eq_ignore_ascii_case("varies")
+	}
+}
+
+impl std::str::FromStr for Wind {
+	type Err = &'static str;
+
+	/// Parses `"8 mph, Out To CF"`, `"0 mph, None"`, `"Calm"`, `"Varies"` and `"5 mph, Varies"`.
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		let mph = |speed: &str| speed.trim().strip_suffix("mph").unwrap_or(speed).trim().parse::<i32>().map(|speed| uom::si::f64::Velocity::new::<uom::si::velocity::mile_per_hour>(f64::from(speed))).map_err(|_| "invalid wind speed");
+		match s.trim().split_once(", ") {
+			Some((speed, direction)) => Ok(Self { speed: Some(mph(speed)?), direction: WindDirectionId::new(direction.trim()) }),
+			None if s.trim().eq_ignore_ascii_case("calm") => Ok(Self { speed: Some(uom::si::f64::Velocity::default()), direction: WindDirectionId::new("Calm") }),
+			None if s.trim().eq_ignore_ascii_case("varies") => Ok(Self { speed: None, direction: WindDirectionId::new("Varies") }),
+			None if s.contains("mph") => Ok(Self { speed: Some(mph(s)?), direction: WindDirectionId::new("None") }),
+			None => Err("invalid wind format"),
+		}
+	}
 }
 
 #[derive(Deserialize)]
@@ -116,13 +324,9 @@ impl TryFrom<__WeatherConditionsStruct> for WeatherConditions {
 
 	fn try_from(value: __WeatherConditionsStruct) -> Result<Self, Self::Error> {
 		Ok(Self {
-			condition: value.condition,
-			temp: value.temp.and_then(|temp| temp.parse::<i32>().ok()).map(|temp| uom::si::f64::ThermodynamicTemperature::new::<uom::si::thermodynamic_temperature::degree_fahrenheit>(temp as f64)),
-			wind: if let Some(wind) = value.wind {
-				let (speed, direction) = wind.split_once(" mph, ").ok_or("invalid wind format")?;
-				let speed = speed.parse::<i32>().map_err(|_| "invalid wind speed")?;
-				Some((uom::si::f64::Velocity::new::<uom::si::velocity::mile_per_hour>(speed as f64), WindDirectionId::new(direction)))
-			} else { None },
+			condition: value.condition.as_deref().map(SkyCondition::from),
+			temp: value.temp.as_deref().and_then(parse_fahrenheit),
+			wind: value.wind.as_deref().map(str::parse).transpose()?,
 		})
 	}
 }
@@ -201,7 +405,7 @@ impl DoubleHeaderKind {
 	}
 }
 
-#[derive(Debug, Deserialize, Copy, Clone, PartialEq, Eq, Deref, DerefMut, From)]
+#[derive(Debug, Deserialize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deref, DerefMut, From)]
 pub struct Inning(usize);
 
 impl Inning {
@@ -219,7 +423,7 @@ impl Display for Inning {
 }
 
 /// Half of the inning.
-#[derive(Debug, Deserialize, Copy, Clone, PartialEq, Eq, Not)]
+#[derive(Debug, Deserialize, Copy, Clone, PartialEq, Eq, Hash, Not)]
 pub enum InningHalf {
 	#[serde(rename = "Top", alias = "top")]
 	Top,
@@ -317,6 +521,13 @@ pub struct SituationCount {
 	pub runner_on_third: bool,
 }
 
+impl SituationCount {
+	#[must_use]
+	pub const fn bases(&self) -> BaseOccupancy {
+		BaseOccupancy { first: self.runner_on_first, second: self.runner_on_second, third: self.runner_on_third }
+	}
+}
+
 /// The classic "R | H | E" and LOB in a scoreboard.
 #[derive(Debug, Deserialize, PartialEq, Eq, Copy, Clone)]
 #[serde(from = "__RHEStruct")]
@@ -423,6 +634,21 @@ pub enum OfficialType {
 	RightField,
 }
 
+impl OfficialType {
+	/// Abbreviation as used in box scores; `HP`, `1B`, `LF`, etc.
+	#[must_use]
+	pub const fn abbreviation(self) -> &'static str {
+		match self {
+			Self::HomePlate => "HP",
+			Self::FirstBase => "1B",
+			Self::SecondBase => "2B",
+			Self::ThirdBase => "3B",
+			Self::LeftField => "LF",
+			Self::RightField => "RF",
+		}
+	}
+}
+
 /// A position in the batting order, 1st, 2nd, 3rd, 4th, etc.
 ///
 /// Note that this number is split in two, the general batting order position is the `major` while if there is a lineup movement then the player would have an increased `minor` since they replace an existing batting order position.
@@ -434,7 +660,7 @@ pub enum OfficialType {
 /// Charlie pinch runs and takes over from then on (major = 1, minor = 2)
 ///
 /// Note: These minors are [`Display`]ed incremented one more than is done internally, so (major = 1, minor = 1) displays as `1st (2)`.
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
 pub struct BattingOrderIndex {
 	pub major: usize,
 	pub minor: usize,
@@ -473,24 +699,6 @@ pub struct Decisions {
 	pub save: Option<NamedPerson>,
 }
 
-/// Game records in stats like exit velocity, hit distance, etc.
-///
-/// Currently unable to actually get data for these though
-#[derive(Debug, Deserialize, PartialEq, Clone)]
-#[serde(rename_all = "camelCase")]
-#[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
-pub struct GameStatLeaders {
-	#[doc(hidden)]
-	#[serde(rename = "hitDistance", default)]
-	pub __distance: IgnoredAny,
-	#[doc(hidden)]
-	#[serde(rename = "hitSpeed", default)]
-	pub __exit_velocity: IgnoredAny,
-	#[doc(hidden)]
-	#[serde(rename = "pitchSpeed", default)]
-	pub __velocity: IgnoredAny,
-}
-
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Display)]
 pub enum Base {
 	#[display("1B")]
@@ -619,6 +827,7 @@ pub(crate) fn deserialize_players_cache<'de, T: DeserializeOwned, D: Deserialize
 ///     }
 /// }).await?;
 /// ```
+#[allow(clippy::struct_excessive_bools, reason = "independent flags of the cursor")]
 #[derive(Debug)]
 pub struct PlayStream {
 	game_id: GameId,
@@ -631,6 +840,8 @@ pub struct PlayStream {
 	current_play_event_idx: usize,
 	current_play_event_review_idx: usize,
 	in_progress_current_play_event_review: bool,
+
+	with_win_probability: bool,
 }
 
 impl PlayStream {
@@ -647,8 +858,26 @@ impl PlayStream {
 			current_play_event_idx: 0,
 			current_play_event_review_idx: 0,
 			in_progress_current_play_event_review: false,
+
+			with_win_probability: false,
 		}
 	}
+
+	/// Also requests the [`WinProbabilityResponse`] every poll and merges it into the plays, see [`Play::win_probability`].
+	#[must_use]
+	pub const fn with_win_probability(mut self) -> Self {
+		self.with_win_probability = true;
+		self
+	}
+
+	async fn fetch(&self) -> Result<LiveFeedResponse, request::Error> {
+		let mut feed = LiveFeedRequest::builder().id(self.game_id).build_and_get().await?;
+		if self.with_win_probability {
+			let win_probability = WinProbabilityRequest::builder().id(self.game_id).build_and_get().await?;
+			feed.live.plays.merge_win_probability(&win_probability);
+		}
+		Ok(feed)
+	}
 }
 
 /// An event in a game, such as the game starting, ending, a [`Play`] (At-Bat) starting, or a [`PlayEvent`] occuring, or a challenge on a play or play event.
@@ -804,15 +1033,41 @@ impl PlayStream {
 	/// # Errors
 	/// See [`request::Error`]
 	pub async fn run_with_custom_error<E: From<request::Error>, F: AsyncFnMut(PlayStreamEvent, &LiveFeedMetadata, &LiveFeedData, &Linescore, &Boxscore) -> Result<ControlFlow<()>, E>>(self, f: F) -> Result<(), E> {
-		let feed = LiveFeedRequest::builder().id(self.game_id).build_and_get().await?;
-		Self::with_presupplied_feed(feed, f).await
+		let feed = self.fetch().await?;
+		self.run_from_feed(feed, f).await
 	}
 
 	/// Variant of the ``run`` function that begins with a pre-supplied [`LiveFeedResponse`], useful if preprocessing was done before the play stream.
 	///
+	/// To configure the stream (such as [`Self::with_win_probability`]), use [`Self::run_with_feed`].
+	///
+	/// # Errors
+	/// See [`request::Error`]
+	pub async fn with_presupplied_feed<E: From<request::Error>, F: AsyncFnMut(PlayStreamEvent, &LiveFeedMetadata, &LiveFeedData, &Linescore, &Boxscore) -> Result<ControlFlow<()>, E>>(feed: LiveFeedResponse, f: F) -> Result<(), E> {
+		Self::new(feed.id).run_with_feed(feed, f).await
+	}
+
+	/// Variant of [`Self::with_presupplied_feed`] that keeps this stream's configuration for later polls.
+	///
+	/// The stream follows the game of the `feed`.
+	///
+	/// ## Examples
+	/// ```no_run
+	/// let mut feed: LiveFeedResponse = ...;
+	/// let win_probability: WinProbabilityResponse = ...;
+	///
+	/// feed.live.plays.merge_win_probability(&win_probability);
+	/// PlayStream::new(feed.id).with_win_probability().run_with_feed(feed, async |event, meta, data, linescore, boxscore| { ... }).await?;
+	/// ```
+	///
 	/// # Errors
 	/// See [`request::Error`]
-	pub async fn with_presupplied_feed<E: From<request::Error>, F: AsyncFnMut(PlayStreamEvent, &LiveFeedMetadata, &LiveFeedData, &Linescore, &Boxscore) -> Result<ControlFlow<()>, E>>(mut feed: LiveFeedResponse, mut f: F) -> Result<(), E> {
+	pub async fn run_with_feed<E: From<request::Error>, F: AsyncFnMut(PlayStreamEvent, &LiveFeedMetadata, &LiveFeedData, &Linescore, &Boxscore) -> Result<ControlFlow<()>, E>>(mut self, feed: LiveFeedResponse, f: F) -> Result<(), E> {
+		self.game_id = feed.id;
+		self.run_from_feed(feed, f).await
+	}
+
+	async fn run_from_feed<E: From<request::Error>, F: AsyncFnMut(PlayStreamEvent, &LiveFeedMetadata, &LiveFeedData, &Linescore, &Boxscore) -> Result<ControlFlow<()>, E>>(mut self, mut feed: LiveFeedResponse, mut f: F) -> Result<(), E> {
 		macro_rules! flow_try {
 			($($t:tt)*) => {
 				match ($($t)*).await? {
@@ -822,7 +1077,6 @@ impl PlayStream {
 			};
 		}
 
-		let mut this = Self::new(feed.id);
 		flow_try!(f(PlayStreamEvent::Start, &feed.meta, &feed.data, &feed.live.linescore, &feed.live.boxscore));
 		
 		loop {
@@ -830,25 +1084,25 @@ impl PlayStream {
 		    
 			let LiveFeedResponse { meta, data, live, .. } = &feed;
 			let LiveFeedLiveData { linescore, boxscore, decisions, leaders, plays } = live;
-			let mut plays = plays.iter().skip(this.current_play_idx);
+			let mut plays = plays.iter().skip(self.current_play_idx);
 
 			if let Some(current_play) = plays.next() {
-				flow_try!(this.run_current_play(&mut f, current_play, meta, data, linescore, boxscore));
+				flow_try!(self.run_current_play(&mut f, current_play, meta, data, linescore, boxscore));
 			}
 			
-			flow_try!(this.run_next_plays(&mut f, plays, meta, data, linescore, boxscore));
+			flow_try!(self.run_next_plays(&mut f, plays, meta, data, linescore, boxscore));
 			
 			if data.status.abstract_game_code.is_finished() && let Some(decisions) = decisions {
-				let _ = f(PlayStreamEvent::GameEnd(decisions, leaders), meta, data, linescore, boxscore).await?;
+				let _ = f(PlayStreamEvent::GameEnd(decisions, &leaders.clone().or_from_plays(&live.plays)), meta, data, linescore, boxscore).await?;
 				return Ok(())
 			}
 
-			this.update_indices(&live.plays);
+			self.update_indices(&live.plays);
 
 			let total_sleep_time = Duration::from_secs(meta.recommended_poll_rate as _);
 			drop(feed);
 			tokio::time::sleep(total_sleep_time.saturating_sub(since_last_request.elapsed())).await;
-		    feed = LiveFeedRequest::builder().id(this.game_id).build_and_get().await?;
+		    feed = self.fetch().await?;
 		}
 	}
 }
@@ -856,7 +1110,7 @@ impl PlayStream {
 #[cfg(test)]
 mod tests {
     use std::ops::ControlFlow;
-    use crate::{cache::RequestableEntrypoint, game::{PlayEvent, PlayStream, PlayStreamEvent}};
+    use crate::{RoofType, cache::RequestableEntrypoint, game::{LabelledValue, PlayEvent, PlayStream, PlayStreamEvent, RoofState, SkyCondition, WeatherConditions, Wind}};
 
 	#[tokio::test]
 	async fn test_play_stream() {
@@ -884,4 +1138,22 @@ mod tests {
 			Ok(ControlFlow::Continue(()))
 		})).await.unwrap();
 	}
+
+	#[test]
+	fn weather_parsing() {
+		let wind = "Calm".parse::<Wind>().unwrap();
+		assert!(wind.is_calm());
+		let wind = "Varies".parse::<Wind>().unwrap();
+		assert!(wind.varies() && wind.speed.is_none());
+		let wind = "12 mph, Out To CF".parse::<Wind>().unwrap();
+		assert!(!wind.is_calm() && &*wind.direction == "Out To CF");
+		assert!("gusty".parse::<Wind>().is_err());
+
+		let values = [LabelledValue { label: "Weather".into(), value: "68 degrees, Roof Closed.".into() }, LabelledValue { label: "Wind".into(), value: "0 mph, None.".into() }];
+		let weather = WeatherConditions::from_labelled_values(&values).unwrap();
+		assert_eq!(weather.condition, Some(SkyCondition::RoofClosed));
+		assert!(weather.wind.as_ref().is_some_and(Wind::is_calm));
+		assert_eq!(weather.roof_state(&RoofType::Retractable), RoofState::Closed);
+		assert_eq!(SkyCondition::from("Hazy"), SkyCondition::Unknown("Hazy".into()));
+	}
 }
@@ -146,6 +146,32 @@ pub struct GamePace {
     pub __pitches_per_pitcher: IgnoredAny,
 }
 
+impl GamePace {
+    /// Average length of a game.
+    #[must_use]
+    pub fn time_per_game(&self) -> Option<TimeDelta> {
+        divide(self.total_game_time, self.games)
+    }
+
+    /// Comparable to [`InGamePace::time_per_plate_appearance`](crate::game::InGamePace::time_per_plate_appearance).
+    #[must_use]
+    pub fn time_per_plate_appearance(&self) -> Option<TimeDelta> {
+        divide(self.total_game_time, self.plate_appearances)
+    }
+
+    /// Comparable to [`InGamePace::time_per_pitch`](crate::game::InGamePace::time_per_pitch).
+    #[must_use]
+    pub fn time_per_pitch(&self) -> Option<TimeDelta> {
+        divide(self.total_game_time, self.num_pitches)
+    }
+}
+
+/// `total` split evenly `count` ways, `None` if `count` is zero.
+pub(super) fn divide(total: TimeDelta, count: usize) -> Option<TimeDelta> {
+    let count = i32::try_from(count).ok().filter(|&count| count > 0)?;
+    Some(total / count)
+}
+
 #[derive(Debug, Deserialize, PartialEq, Clone, Deref, DerefMut)]
 #[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
 pub struct TeamGamePace {
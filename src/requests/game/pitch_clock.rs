@@ -0,0 +1,222 @@
+//! Pitch timer and disengagement analytics; violations, time between pitches, and running game after the second disengagement.
+
+use std::num::NonZeroUsize;
+
+use chrono::TimeDelta;
+use fxhash::FxHashMap;
+
+use crate::{game::{Play, PlayEvent, Plays, Violation, ViolationKind}, meta::EventType, person::PersonId};
+
+/// What a [`Violation`] cost the offending team.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ViolationPenalty {
+	/// Pitcher or catcher violation.
+	Ball,
+	/// Batter violation.
+	Strike,
+	/// Third disengagement without recording an out; runners advance.
+	Balk,
+}
+
+impl PlayEvent {
+	/// Violation called on this event, see [`ViolationPenalty`].
+	#[must_use]
+	pub const fn violation(&self) -> Option<&Violation> {
+		match self {
+			Self::Action { details, .. } => details.violation.as_ref(),
+			Self::Pitch { details, .. } => details.violation.as_ref(),
+			Self::Stepoff { details, .. } => details.violation.as_ref(),
+			Self::NoPitch { details, .. } => details.violation.as_ref(),
+			Self::Pickoff { details, .. } => details.violation.as_ref(),
+		}
+	}
+
+	/// The automatic ball, strike, or balk resulting from a violation on this event.
+	#[must_use]
+	pub fn violation_penalty(&self) -> Option<ViolationPenalty> {
+		if let Self::Action { details, .. } = self && details.event == EventType::DisengagementViolation {
+			return Some(ViolationPenalty::Balk);
+		}
+		let violation = self.violation()?;
+		let (is_ball, is_strike) = match self {
+			Self::Pitch { details, .. } => (details.is_ball, details.is_strike),
+			Self::NoPitch { details, .. } => (details.is_ball, details.is_strike),
+			_ => (false, false),
+		};
+		if is_ball {
+			Some(ViolationPenalty::Ball)
+		} else if is_strike {
+			Some(ViolationPenalty::Strike)
+		} else if violation.kind == ViolationKind::Disengagement {
+			Some(ViolationPenalty::Balk)
+		} else {
+			None
+		}
+	}
+
+	/// Pitcher disengagements so far in the plate appearance, as of this event.
+	#[must_use]
+	pub const fn disengagement_number(&self) -> Option<NonZeroUsize> {
+		match self {
+			Self::Action { details, .. } => details.disengagements,
+			Self::Pitch { details, .. } => details.disengagements,
+			Self::Stepoff { details, .. } => details.disengagements,
+			Self::NoPitch { details, .. } => details.disengagements,
+			Self::Pickoff { details, .. } => details.disengagements,
+		}
+	}
+
+	/// Whether the pitcher disengaged the rubber; a step off or a pickoff attempt by the pitcher (not the catcher).
+	#[must_use]
+	pub const fn is_disengagement(&self) -> bool {
+		match self {
+			Self::Stepoff { .. } => true,
+			Self::Pickoff { details, .. } => !details.from_catcher,
+			_ => false,
+		}
+	}
+}
+
+/// A [`Violation`] and where it happened.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViolationRecord {
+	pub at_bat_idx: usize,
+	/// Index of the event within [`Play::play_events`].
+	pub play_event_idx: usize,
+	pub violation: Violation,
+	pub penalty: Option<ViolationPenalty>,
+}
+
+/// Pitch timer and disengagement totals, for a game or a single pitcher.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PitchClockSummary {
+	pub plate_appearances: usize,
+	pub pitches: usize,
+	/// See [`PlayEvent::is_disengagement`].
+	pub disengagements: usize,
+	/// For a pitcher, only the violations they committed.
+	pub violations: Vec<ViolationRecord>,
+	/// Time between consecutive pitches in the same plate appearance, start to start.
+	pub pitch_intervals: Vec<TimeDelta>,
+	/// Stolen base attempts (successful or not) made after the pitcher's second disengagement of the plate appearance, when a third would be a balk.
+	pub steal_attempts_after_second_disengagement: usize,
+	pub stolen_bases_after_second_disengagement: usize,
+}
+
+impl PitchClockSummary {
+	/// `None` if there were no plate appearances.
+	#[must_use]
+	#[allow(clippy::cast_precision_loss, reason = "a game has far fewer than 2^52 plate appearances")]
+	pub fn disengagements_per_plate_appearance(&self) -> Option<f64> {
+		(self.plate_appearances > 0).then(|| self.disengagements as f64 / self.plate_appearances as f64)
+	}
+
+	/// Mean of [`pitch_intervals`](Self::pitch_intervals), `None` if there are none.
+	#[must_use]
+	pub fn average_time_between_pitches(&self) -> Option<TimeDelta> {
+		let count = i32::try_from(self.pitch_intervals.len()).ok().filter(|&count| count > 0)?;
+		Some(self.pitch_intervals.iter().sum::<TimeDelta>() / count)
+	}
+}
+
+/// Pitch timer and disengagement analytics for a game, see [`Plays::pitch_clock`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PitchClockReport {
+	pub game: PitchClockSummary,
+	pub pitchers: FxHashMap<PersonId, PitchClockSummary>,
+}
+
+impl Plays {
+	/// Pitch timer violations, disengagements, and time between pitches for the whole game and each pitcher.
+	///
+	/// ## Examples
+	/// ```no_run
+	/// let plays: Plays = ...;
+	///
+	/// let report = plays.pitch_clock();
+	/// for (pitcher, summary) in &report.pitchers {
+	///     println!("{pitcher}: {:?} between pitches, {} violations", summary.average_time_between_pitches(), summary.violations.len());
+	/// }
+	/// ```
+	#[must_use]
+	pub fn pitch_clock(&self) -> PitchClockReport {
+		let mut report = PitchClockReport::default();
+		for play in self {
+			report.record_play(play);
+		}
+		report
+	}
+}
+
+impl PitchClockReport {
+	fn record_play(&mut self, play: &Play) {
+		if play.result.completed_play_details.as_ref().is_some_and(|details| details.event.is_plate_appearance()) {
+			self.game.plate_appearances += 1;
+			self.pitchers.entry(play.matchup.pitcher.id).or_default().plate_appearances += 1;
+		}
+
+		let pitchers = play.event_pitchers();
+		let mut previous_pitch: Option<(&PlayEvent, PersonId)> = None;
+		let mut past_second_disengagement = false;
+		for (play_event_idx, (event, &pitcher)) in play.play_events.iter().zip(&pitchers).enumerate() {
+			let summaries = [&mut self.game, self.pitchers.entry(pitcher).or_default()];
+			if let PlayEvent::Action { details, .. } = event && past_second_disengagement && (details.event.is_stolen_base() || details.event.is_caught_stealing()) {
+				for summary in summaries {
+					summary.steal_attempts_after_second_disengagement += 1;
+					summary.stolen_bases_after_second_disengagement += usize::from(details.event.is_stolen_base());
+				}
+			} else if let PlayEvent::Pitch { .. } = event {
+				let interval = previous_pitch.filter(|&(_, previous_pitcher)| previous_pitcher == pitcher).map(|(previous, _)| event.start_timestamp - previous.start_timestamp);
+				for summary in summaries {
+					summary.pitches += 1;
+					summary.pitch_intervals.extend(interval);
+				}
+				previous_pitch = Some((event, pitcher));
+			} else if event.is_disengagement() {
+				for summary in summaries {
+					summary.disengagements += 1;
+				}
+			}
+			past_second_disengagement |= event.disengagement_number().is_some_and(|number| number.get() >= 2);
+			self.record_violation(play, play_event_idx, event, pitcher);
+		}
+	}
+
+	fn record_violation(&mut self, play: &Play, play_event_idx: usize, event: &PlayEvent, pitcher: PersonId) {
+		let Some(violation) = event.violation() else { return };
+		let record = ViolationRecord {
+			at_bat_idx: play.about.at_bat_idx,
+			play_event_idx,
+			violation: violation.clone(),
+			penalty: event.violation_penalty(),
+		};
+		if violation.player.as_ref().is_some_and(|player| player.id == pitcher) {
+			self.pitchers.entry(pitcher).or_default().violations.push(record.clone());
+		}
+		self.game.violations.push(record);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::TimeDelta;
+
+	use crate::game::LiveFeedRequest;
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_pitch_clock() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let report = feed.live.plays.pitch_clock();
+		let plate_appearances = feed.live.plays.iter().filter(|play| play.result.completed_play_details.as_ref().is_some_and(|details| details.event.is_plate_appearance())).count();
+		assert_eq!(report.game.plate_appearances, plate_appearances);
+		assert!(report.game.disengagements_per_plate_appearance().is_some());
+		// Ohtani started for the Dodgers
+		assert!(report.pitchers.get(&crate::person::PersonId::new(660_271)).is_some_and(|summary| summary.plate_appearances > 0));
+		let average = report.game.average_time_between_pitches().unwrap();
+		assert!((TimeDelta::seconds(10)..TimeDelta::seconds(40)).contains(&average), "{average} between pitches");
+		assert_eq!(report.game.pitches, report.pitchers.values().map(|summary| summary.pitches).sum::<usize>());
+		assert_eq!(report.game.plate_appearances, report.pitchers.values().map(|summary| summary.plate_appearances).sum::<usize>());
+		assert!(report.game.pitch_intervals.iter().all(|interval| *interval >= TimeDelta::zero()));
+	}
+}
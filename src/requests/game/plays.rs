@@ -7,7 +7,7 @@ use serde::{Deserialize, Deserializer, de::IgnoredAny};
 use serde_with::{serde_as, DefaultOnNull, DefaultOnError};
 use uuid::Uuid;
 
-use crate::{Copyright, Handedness, HomeAway, game::{AtBatCount, Base, BattingOrderIndex, ContactHardness, GameId, Inning, InningHalf}, meta::{EventType, HitTrajectory, NamedPosition, PitchCodeId, PitchType, ReviewReasonId}, person::{NamedPerson, PersonId}, request::RequestURL, stats::raw::{HittingHotColdZones, PitchingHotColdZones, StrikeZoneSection}, team::TeamId};
+use crate::{Copyright, Handedness, HomeAway, game::{AtBatCount, Base, BattingOrderIndex, ContactHardness, GameId, Inning, InningHalf, WinProbabilityData}, meta::{EventType, HitTrajectory, NamedPosition, PitchCodeId, PitchType, PositionCode, ReviewReasonId}, person::{NamedPerson, PersonId}, request::RequestURL, stats::raw::{HittingHotColdZones, PitchingHotColdZones, StrikeZoneSection}, team::TeamId};
 
 /// A collection of plays, often a whole game's worth.
 #[allow(clippy::struct_field_names, clippy::unsafe_derive_deserialize, reason = "not relevant here")]
@@ -66,6 +66,28 @@ impl Plays {
         self.plays
     }
 
+    /// Recomputes the [`Self::scoring_plays`] and [`Self::by_inning`] caches after the plays were replaced.
+    pub(super) fn rebuild_indices(&mut self) {
+        self.scoring_play_indices = self.plays.iter().enumerate()
+            .filter(|(_, play)| play.about.is_scoring_play == Some(true))
+            .map(|(idx, _)| idx)
+            .collect();
+        self.play_indices_by_inning = self.plays.iter().enumerate()
+            .collect::<Vec<_>>()
+            .chunk_by(|(_, a), (_, b)| a.about.inning == b.about.inning)
+            .map(|inning| {
+                let half = |half: InningHalf| inning.iter().filter(|(_, play)| play.about.inning_half == half).map(|(idx, _)| *idx).collect();
+                InningPlaysIndices {
+                    start: inning.first().map_or(0, |(idx, _)| *idx),
+                    end: inning.last().map_or(0, |(idx, _)| *idx),
+                    top_indices: half(InningHalf::Top),
+                    bottom_indices: half(InningHalf::Bottom),
+                    __balls_in_play: IgnoredAny,
+                }
+            })
+            .collect();
+    }
+
     /// Iterator over a list of scoring plays.
     ///
     /// ## Examples
@@ -165,6 +187,10 @@ pub struct Play {
     #[serde(rename = "playEndTime", deserialize_with = "crate::deserialize_datetime")]
     pub play_end_timestamp: DateTime<Utc>,
 
+    /// Win probability after the play, only present once merged in with [`Plays::merge_win_probability`].
+    #[serde(skip)]
+    pub win_probability: Option<WinProbabilityData>,
+
     #[doc(hidden)]
     #[serde(rename = "pitchIndex", default)]
     pub __pitch_indices: IgnoredAny,
@@ -179,6 +205,52 @@ pub struct Play {
     pub __at_bat_index: IgnoredAny,
 }
 
+impl Play {
+    /// Runners on base and outs at the start of the play, given the `previous` play of the game.
+    ///
+    /// Includes runners placed on base to start an inning, such as the automatic runner in extra innings.
+    #[must_use]
+    pub fn starting_situation(&self, previous: Option<&Self>) -> (BaseOccupancy, u8) {
+        let previous = previous.filter(|previous| previous.about.inning == self.about.inning && previous.about.inning_half == self.about.inning_half);
+        let mut bases = previous.map(|previous| previous.matchup.ending_bases()).unwrap_or_default();
+        for runner in &self.runners {
+            bases.set(runner.movement.origin_base, true);
+        }
+        (bases, previous.map_or(0, |previous| previous.count.outs))
+    }
+
+    /// Runners on base before the `play_event_idx`th event, given the runners on base at the start of the play.
+    #[must_use]
+    pub fn bases_before(&self, play_event_idx: usize, mut bases: BaseOccupancy) -> BaseOccupancy {
+        for idx in 0..play_event_idx {
+            let moved = self.runners.iter().filter(|runner| runner.details.play_event_index == Some(idx)).collect::<Vec<_>>();
+            // vacate every base first so a runner can take the base another just left.
+            for runner in &moved {
+                bases.set(runner.movement.start_base, false);
+            }
+            for runner in moved.into_iter().filter(|runner| !runner.movement.is_out) {
+                bases.set(runner.movement.end_base, true);
+            }
+        }
+        bases
+    }
+
+    /// The pitcher on the mound for each of the [`Self::play_events`], accounting for pitching changes mid plate appearance.
+    #[must_use]
+    pub fn event_pitchers(&self) -> Vec<PersonId> {
+        let mut pitcher = self.matchup.pitcher.id;
+        let mut pitchers = self.play_events.iter().rev().map(|event| {
+            let current = pitcher;
+            if let PlayEvent::Action { details, common, .. } = event && details.event == EventType::PitchingSubstitution && let Some(replaced) = common.replaced_player {
+                pitcher = replaced;
+            }
+            current
+        }).collect::<Vec<_>>();
+        pitchers.reverse();
+        pitchers
+    }
+}
+
 /// The result of a play, such as a Strikeout, Home Run, etc.
 #[derive(Debug, Deserialize, PartialEq, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -309,6 +381,59 @@ pub struct PlayMatchup {
     pub splits: ApplicablePlayMatchupSplits,
 }
 
+impl PlayMatchup {
+    /// Runners on base at the end of the play.
+    #[must_use]
+    pub const fn ending_bases(&self) -> BaseOccupancy {
+        BaseOccupancy { first: self.post_on_first.is_some(), second: self.post_on_second.is_some(), third: self.post_on_third.is_some() }
+    }
+}
+
+/// Which bases have a runner on them.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Hash, Default)]
+pub struct BaseOccupancy {
+    pub first: bool,
+    pub second: bool,
+    pub third: bool,
+}
+
+impl BaseOccupancy {
+    #[must_use]
+    pub const fn is_occupied(&self, base: Base) -> bool {
+        match base {
+            Base::First => self.first,
+            Base::Second => self.second,
+            Base::Third => self.third,
+            Base::Home => false,
+        }
+    }
+
+    #[must_use]
+    pub const fn runners(&self) -> usize {
+        self.first as usize + self.second as usize + self.third as usize
+    }
+
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        !self.first && !self.second && !self.third
+    }
+
+    /// Runners on second and/or third.
+    #[must_use]
+    pub const fn is_scoring_position(&self) -> bool {
+        self.second || self.third
+    }
+
+    const fn set(&mut self, base: Option<Base>, occupied: bool) {
+        match base {
+            Some(Base::First) => self.first = occupied,
+            Some(Base::Second) => self.second = occupied,
+            Some(Base::Third) => self.third = occupied,
+            Some(Base::Home) | None => {},
+        }
+    }
+}
+
 /// Batter, Pitcher, and Men-On-Base splits; unknown type.
 #[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -382,17 +507,17 @@ pub struct RunnerDetails {
     #[serde(rename = "event", default)]
     pub __event_type: IgnoredAny,
 
-    #[doc(hidden)]
-    #[serde(rename = "responsiblePitcher", default)]
-    pub __responsible_pitcher: IgnoredAny,
+    /// Pitcher charged with the runner if they score; differs from the [`PlayMatchup`] pitcher for inherited runners.
+    #[serde(default)]
+    pub responsible_pitcher: Option<PersonId>,
 
     #[doc(hidden)]
     #[serde(rename = "teamUnearned", default)]
     pub __team_unearned: IgnoredAny,
     
-    #[doc(hidden)]
+    /// Index into [`Play::play_events`] of the event this movement happened on.
     #[serde(rename = "playIndex", default)]
-    pub __play_index: IgnoredAny,
+    pub play_event_index: Option<usize>,
 }
 
 /// Reasons for baserunner movement
@@ -607,6 +732,20 @@ pub enum CreditKind {
     CatchersInterference,
 }
 
+impl CreditKind {
+    /// Any kind of error charged to the fielder.
+    #[must_use]
+    pub const fn is_error(self) -> bool {
+        matches!(self, Self::FieldingError | Self::ThrowingError | Self::DroppedBallError)
+    }
+
+    /// Whether the fielder handled the ball on the way to an out; putouts, assists and deflections.
+    #[must_use]
+    pub const fn is_assist_or_putout(self) -> bool {
+        matches!(self, Self::Putout | Self::Assist | Self::OutfieldAssist | Self::Deflection)
+    }
+}
+
 /// # Errors
 /// See `D::Error`, likely [`serde_json::Error`]
 pub fn deserialize_review_data<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<ReviewData>, D::Error> {
@@ -784,10 +923,9 @@ pub struct ActionPlayDetails {
     #[serde(rename = "type", default)]
     pub __type: IgnoredAny,
 
-    // redundant
-    #[doc(hidden)]
-    #[serde(rename = "violation", default)]
-    pub __violation: IgnoredAny,
+    /// Pitch timer (or other) violation committed on this event, see [`PlayEvent::violation_penalty`].
+    #[serde(default)]
+    pub violation: Option<Violation>,
 }
 
 #[allow(clippy::struct_excessive_bools, reason = "inapplicable")]
@@ -827,14 +965,13 @@ pub struct PitchPlayDetails {
     #[serde(rename = "code", default)]
     pub __code: IgnoredAny,
 
-    // redundant
-    #[doc(hidden)]
-    #[serde(rename = "violation", default)]
-    pub __violation: IgnoredAny,
+    /// Pitch timer (or other) violation committed on this event, see [`PlayEvent::violation_penalty`].
+    #[serde(default)]
+    pub violation: Option<Violation>,
 }
 
 #[allow(clippy::struct_excessive_bools, reason = "inapplicable")]
-#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
 pub struct StepoffPlayDetails {
@@ -849,10 +986,9 @@ pub struct StepoffPlayDetails {
     #[serde(rename = "disengagementNum", default)]
     pub disengagements: Option<NonZeroUsize>,
 
-    // redundant
-    #[doc(hidden)]
-    #[serde(rename = "violation", default)]
-    pub __violation: IgnoredAny,
+    /// Pitch timer (or other) violation committed on this event, see [`PlayEvent::violation_penalty`].
+    #[serde(default)]
+    pub violation: Option<Violation>,
 }
 
 #[allow(clippy::struct_excessive_bools, reason = "inapplicable")]
@@ -886,14 +1022,13 @@ pub struct NoPitchPlayDetails {
     #[serde(rename = "code", default)]
     pub __code: IgnoredAny,
 
-    // redundant
-    #[doc(hidden)]
-    #[serde(rename = "violation", default)]
-    pub __violation: IgnoredAny,
+    /// Pitch timer (or other) violation committed on this event, see [`PlayEvent::violation_penalty`].
+    #[serde(default)]
+    pub violation: Option<Violation>,
 }
 
 #[allow(clippy::struct_excessive_bools, reason = "inapplicable")]
-#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
 pub struct PickoffPlayDetails {
@@ -909,10 +1044,47 @@ pub struct PickoffPlayDetails {
     #[serde(rename = "disengagementNum", default)]
     pub disengagements: Option<NonZeroUsize>,
 
-    // redundant
-    #[doc(hidden)]
-    #[serde(rename = "violation", default)]
-    pub __violation: IgnoredAny,
+    /// Pitch timer (or other) violation committed on this event, see [`PlayEvent::violation_penalty`].
+    #[serde(default)]
+    pub violation: Option<Violation>,
+}
+
+/// A rule violation called on a play event, most often the pitch timer.
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
+pub struct Violation {
+    #[serde(rename = "type")]
+    pub kind: ViolationKind,
+    /// Pitcher Pitch Timer Violation, Batter Timer Violation, etc.
+    #[serde(default)]
+    pub description: String,
+    /// Player who committed the violation.
+    pub player: Option<NamedPerson>,
+}
+
+/// Kind of [`Violation`].
+#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+#[serde(from = "String")]
+pub enum ViolationKind {
+    /// Pitcher not starting their motion, or batter not ready, before the pitch timer expires.
+    PitchTimer,
+    /// Pitcher disengaging the rubber more than twice in a plate appearance without recording an out.
+    Disengagement,
+    /// Infielders not positioned as required by the shift restrictions.
+    DefensiveShift,
+    Other(String),
+}
+
+impl From<String> for ViolationKind {
+    fn from(value: String) -> Self {
+        match &*value {
+            "pitch_timer" | "pitchTimer" => Self::PitchTimer,
+            "disengagement" => Self::Disengagement,
+            "defensive_shift" | "defensiveShift" => Self::DefensiveShift,
+            _ => Self::Other(value),
+        }
+    }
 }
 
 /// Statistical data regarding a pitch.
@@ -1275,6 +1447,21 @@ pub struct HitData {
     /// sometimes just takes a second to be present
     pub contact_hardness: Option<ContactHardness>,
     pub statcast: Option<StatcastHitData>,
+    /// Position of the fielder the ball was hit towards, `"8"` for center field, etc.
+    pub location: Option<PositionCode>,
+    /// Where the ball was fielded (or landed), see [`HitCoordinates::field_position`].
+    pub coordinates: Option<HitCoordinates>,
+    /// Whether the ball was barreled, as determined by Statcast; see [`StatcastHitData::is_barrel`] to compute it when absent.
+    pub is_barrel: Option<bool>,
+}
+
+/// Gameday coordinates of a batted ball.
+///
+/// Measured in Gameday units from the top left of the field image, `y` increasing towards home plate, see [`HitCoordinates::field_position`] for feet.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct HitCoordinates {
+    pub x: f64,
+    pub y: f64,
 }
 
 #[serde_as]
@@ -1292,21 +1479,34 @@ struct __HitDataStruct {
     #[serde(flatten, default)]
     statcast: Option<StatcastHitData>,
 
-    #[doc(hidden)]
-    #[serde(rename = "location", default)]
-    __location: IgnoredAny,
+    #[serde(default)]
+    location: Option<PositionCode>,
 
-    #[doc(hidden)]
-    #[serde(rename = "coordinates")]
-    __coordinates: IgnoredAny,
+    #[serde(default)]
+    coordinates: __HitCoordinatesStruct,
+
+    #[serde(default)]
+    is_barrel: Option<bool>,
+}
+
+#[doc(hidden)]
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
+struct __HitCoordinatesStruct {
+    coord_x: Option<f64>,
+    coord_y: Option<f64>,
 }
 
 impl From<__HitDataStruct> for HitData {
-    fn from(__HitDataStruct { hit_trajectory, contact_hardness, statcast, .. }: __HitDataStruct) -> Self {
+    fn from(__HitDataStruct { hit_trajectory, contact_hardness, statcast, location, coordinates, is_barrel }: __HitDataStruct) -> Self {
         Self {
             hit_trajectory: hit_trajectory.or_else(|| statcast.as_ref().map(|statcast| statcast.launch_angle).map(HitTrajectory::from_launch_angle)),
             contact_hardness,
             statcast,
+            location,
+            coordinates: coordinates.coord_x.zip(coordinates.coord_y).map(|(x, y)| HitCoordinates { x, y }),
+            is_barrel,
         }
     }
 }
@@ -0,0 +1,237 @@
+//! Composable filters over plays and pitches, see [`Predicate`].
+
+use std::ops::{BitAnd, BitOr, Not};
+
+use crate::{Handedness, game::{AtBatCount, BaseOccupancy, GameId, Inning, InningHalf, LiveFeedResponse, Play, PlayEvent, Plays}, meta::{EventType, HitTrajectory, PitchCodeId, PitchTypeId}, person::PersonId};
+
+/// A condition on the game situation of a pitch or play, combined with `&`, `|` and `!`.
+///
+/// ## Examples
+/// ```no_run
+/// let plays: Plays = ...;
+/// let pitcher: PersonId = ...;
+///
+/// // all 3-2 sliders thrown by `pitcher` to left-handed batters with runners in scoring position
+/// let query = Predicate::Pitcher(pitcher)
+///     & Predicate::PitchType(PitchTypeId::new("SL"))
+///     & Predicate::Count { balls: 3, strikes: 2 }
+///     & Predicate::BatSide(Handedness::Left)
+///     & Predicate::RunnersInScoringPosition;
+///
+/// for pitch in query.pitches(&plays) {
+///     println!("{} in the {}", pitch.play.matchup.batter.full_name, pitch.play.about.inning);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Predicate {
+	Batter(PersonId),
+	/// The pitcher on the mound, accounting for pitching changes mid plate appearance.
+	Pitcher(PersonId),
+	/// Side the batter is hitting from, never [`Handedness::Switch`].
+	BatSide(Handedness),
+	PitchHand(Handedness),
+	PitchType(PitchTypeId),
+	/// The call on the pitch, such as `"C"` for a called strike.
+	PitchCode(PitchCodeId),
+	/// Count before the pitch.
+	Count { balls: u8, strikes: u8 },
+	/// Outs before the pitch.
+	Outs(u8),
+	Inning(Inning),
+	InningHalf(InningHalf),
+	/// Runners on base before the pitch, exactly.
+	Bases(BaseOccupancy),
+	/// A runner on second and/or third before the pitch.
+	RunnersInScoringPosition,
+	/// Result of the play, including plays without a pitch such as an automatic intentional walk; for pitches, only the last pitch of the play.
+	Event(EventType),
+	/// Trajectory of the ball put in play.
+	HitTrajectory(HitTrajectory),
+	/// Every predicate matches, `true` if empty.
+	All(Vec<Self>),
+	/// At least one predicate matches, `false` if empty.
+	Any(Vec<Self>),
+	Not(Box<Self>),
+}
+
+/// A pitch (or play) and the game situation before it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayContext<'a> {
+	/// `None` when queried from [`Plays`] rather than a [`LiveFeedResponse`].
+	pub game: Option<GameId>,
+	pub play: &'a Play,
+	/// Index of the pitch within [`Play::play_events`]; for plays, the last pitch. `None` for a play without a pitch.
+	pub play_event_idx: Option<usize>,
+	pub pitcher: PersonId,
+	/// Count before the pitch.
+	pub count: AtBatCount,
+	/// Runners on base before the pitch.
+	pub bases: BaseOccupancy,
+}
+
+impl<'a> PlayContext<'a> {
+	#[must_use]
+	pub fn pitch(&self) -> Option<&'a PlayEvent> {
+		self.play.play_events.get(self.play_event_idx?)
+	}
+
+	/// Whether this pitch ended the plate appearance.
+	#[must_use]
+	pub fn is_last_pitch(&self) -> bool {
+		self.play_event_idx.is_some() && self.play_event_idx == last_pitch_idx(self.play)
+	}
+}
+
+impl Predicate {
+	/// Whether the pitch (or play) in `context` satisfies this predicate.
+	#[must_use]
+	pub fn matches(&self, context: &PlayContext) -> bool {
+		let play = context.play;
+		match self {
+			Self::Batter(batter) => play.matchup.batter.id == *batter,
+			Self::Pitcher(pitcher) => context.pitcher == *pitcher,
+			Self::BatSide(side) => play.matchup.bat_side == *side,
+			Self::PitchHand(hand) => play.matchup.pitch_hand == *hand,
+			Self::PitchType(pitch_type) => matches!(context.pitch(), Some(PlayEvent::Pitch { details, .. }) if details.pitch_type.id == *pitch_type),
+			Self::PitchCode(code) => matches!(context.pitch(), Some(PlayEvent::Pitch { details, .. }) if details.call == *code),
+			Self::Count { balls, strikes } => context.count.balls == *balls && context.count.strikes == *strikes,
+			Self::Outs(outs) => context.count.outs == *outs,
+			Self::Inning(inning) => play.about.inning == *inning,
+			Self::InningHalf(half) => play.about.inning_half == *half,
+			Self::Bases(bases) => context.bases == *bases,
+			Self::RunnersInScoringPosition => context.bases.is_scoring_position(),
+			Self::Event(event) => context.play_event_idx == last_pitch_idx(play) && play.result.completed_play_details.as_ref().is_some_and(|details| details.event == *event),
+			Self::HitTrajectory(trajectory) => matches!(context.pitch(), Some(PlayEvent::Pitch { hit_data: Some(hit_data), .. }) if hit_data.hit_trajectory == Some(*trajectory)),
+			Self::All(predicates) => predicates.iter().all(|predicate| predicate.matches(context)),
+			Self::Any(predicates) => predicates.iter().any(|predicate| predicate.matches(context)),
+			Self::Not(predicate) => !predicate.matches(context),
+		}
+	}
+
+	/// Every pitch in `plays` that matches.
+	#[must_use]
+	pub fn pitches<'a>(&self, plays: &'a Plays) -> Vec<PlayContext<'a>> {
+		contexts(plays, None, true).filter(|context| self.matches(context)).collect()
+	}
+
+	/// Every play in `plays` whose last pitch (and the situation before it) matches, or the play itself if it had no pitch.
+	#[must_use]
+	pub fn plays<'a>(&self, plays: &'a Plays) -> Vec<PlayContext<'a>> {
+		contexts(plays, None, false).filter(|context| self.matches(context)).collect()
+	}
+
+	/// [`Self::pitches`] across several games, such as a series or season.
+	#[must_use]
+	pub fn pitches_in<'a>(&self, games: impl IntoIterator<Item = &'a LiveFeedResponse>) -> Vec<PlayContext<'a>> {
+		games.into_iter().flat_map(|feed| contexts(&feed.live.plays, Some(feed.id), true)).filter(|context| self.matches(context)).collect()
+	}
+
+	/// [`Self::plays`] across several games, such as a series or season.
+	#[must_use]
+	pub fn plays_in<'a>(&self, games: impl IntoIterator<Item = &'a LiveFeedResponse>) -> Vec<PlayContext<'a>> {
+		games.into_iter().flat_map(|feed| contexts(&feed.live.plays, Some(feed.id), false)).filter(|context| self.matches(context)).collect()
+	}
+}
+
+impl BitAnd for Predicate {
+	type Output = Self;
+
+	fn bitand(self, rhs: Self) -> Self::Output {
+		match self {
+			Self::All(mut predicates) => {
+				predicates.push(rhs);
+				Self::All(predicates)
+			},
+			lhs => Self::All(vec![lhs, rhs]),
+		}
+	}
+}
+
+impl BitOr for Predicate {
+	type Output = Self;
+
+	fn bitor(self, rhs: Self) -> Self::Output {
+		match self {
+			Self::Any(mut predicates) => {
+				predicates.push(rhs);
+				Self::Any(predicates)
+			},
+			lhs => Self::Any(vec![lhs, rhs]),
+		}
+	}
+}
+
+impl Not for Predicate {
+	type Output = Self;
+
+	fn not(self) -> Self::Output {
+		match self {
+			Self::Not(predicate) => *predicate,
+			predicate => Self::Not(Box::new(predicate)),
+		}
+	}
+}
+
+fn last_pitch_idx(play: &Play) -> Option<usize> {
+	play.play_events.iter().rposition(|event| matches!(event, PlayEvent::Pitch { .. }))
+}
+
+/// Situations before every pitch, or before the last pitch of every play when not `every_pitch`.
+fn contexts(plays: &Plays, game: Option<GameId>, every_pitch: bool) -> impl Iterator<Item = PlayContext<'_>> {
+	plays.iter().enumerate().flat_map(move |(idx, play)| {
+		let (bases, outs) = play.starting_situation(idx.checked_sub(1).map(|previous| &plays[previous]));
+		let pitchers = play.event_pitchers();
+		let pitch_indices = if every_pitch {
+			play.play_events.iter().enumerate().filter(|(_, event)| matches!(event, PlayEvent::Pitch { .. })).map(|(idx, _)| Some(idx)).collect::<Vec<_>>()
+		} else {
+			vec![last_pitch_idx(play)]
+		};
+		pitch_indices.into_iter().map(move |play_event_idx| {
+			let event_idx = play_event_idx.unwrap_or(play.play_events.len());
+			PlayContext {
+				game,
+				play,
+				play_event_idx,
+				pitcher: pitchers.get(event_idx).copied().unwrap_or(play.matchup.pitcher.id),
+				count: event_idx.checked_sub(1).and_then(|previous| play.play_events.get(previous)).map_or(AtBatCount { balls: 0, strikes: 0, outs }, |previous| previous.count),
+				bases: play.bases_before(event_idx, bases),
+			}
+		})
+	})
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{LiveFeedRequest, PlayEvent, Predicate};
+	use crate::meta::EventType;
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_query() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let plays = &feed.live.plays;
+		let full_count = Predicate::Count { balls: 3, strikes: 2 };
+		let full_count_pitches = full_count.pitches(plays);
+		assert!(full_count_pitches.iter().all(|pitch| pitch.count.balls == 3 && pitch.count.strikes == 2));
+		assert_eq!(full_count_pitches.len() + (!full_count).pitches(plays).len(), Predicate::All(vec![]).pitches(plays).len());
+
+		let home_runs = Predicate::Event(EventType::HomeRun).plays(plays);
+		assert_ne!(home_runs.len(), 0);
+		assert_eq!(home_runs.len(), Predicate::Event(EventType::HomeRun).pitches_in([&feed]).len());
+		assert!(Predicate::RunnersInScoringPosition.pitches(plays).iter().all(|pitch| pitch.bases.second || pitch.bases.third));
+	}
+
+	#[tokio::test]
+	async fn pitchless_play_event() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let mut plays = feed.live.plays;
+		// SAFETY: neither the scoring plays nor the innings change
+		let play = unsafe { plays.plays_mut() }.first_mut().unwrap();
+		play.play_events.retain(|event| !matches!(event, PlayEvent::Pitch { .. }));
+		play.result.completed_play_details.as_mut().unwrap().event = EventType::IntentionalWalk;
+
+		let walks = Predicate::Event(EventType::IntentionalWalk).plays(&plays);
+		assert!(walks.iter().any(|context| context.play.about.at_bat_idx == 0 && context.play_event_idx.is_none()));
+		assert!(Predicate::Event(EventType::IntentionalWalk).pitches(&plays).iter().all(|context| context.play.about.at_bat_idx != 0));
+	}
+}
@@ -0,0 +1,262 @@
+//! A short natural-language recap of a game, built from structured [`RecapFacts`] and rendered through overridable [`RecapTemplates`].
+//!
+//! ## Examples
+//! ```no_run
+//! let feed: LiveFeedResponse = ...;
+//!
+//! let templates = RecapTemplates {
+//!     save: "{pitcher} shut the door.".to_owned(),
+//!     ..RecapTemplates::default()
+//! };
+//! println!("{}", RecapFacts::new(&feed).render(&templates));
+//! ```
+
+use std::fmt::Display;
+
+use fxhash::FxHashMap;
+
+use crate::{HomeAway, TeamSide, game::{Decisions, GameId, InningHalf, LiveFeedResponse, Play, TopPerformer}, person::{NamedPerson, PersonId}, team::TeamName};
+
+/// Facts worth mentioning in a recap of a game, see [`Self::new`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct RecapFacts<'a> {
+	pub game: GameId,
+	pub teams: HomeAway<&'a TeamName>,
+	pub score: HomeAway<usize>,
+	/// Innings played, more than [`Self::scheduled_innings`] in extra innings.
+	pub innings: usize,
+	pub scheduled_innings: usize,
+	/// `None` if the game is tied (or hasn't started).
+	pub winner: Option<TeamSide>,
+	pub decisions: Option<&'a Decisions>,
+	/// Hits that tied the game or gave the batting team the lead, in order.
+	pub key_hits: Vec<&'a Play>,
+	/// The play with the largest change in win probability if it was merged in with [`Plays::merge_win_probability`](crate::game::Plays::merge_win_probability), otherwise the most captivating play.
+	pub turning_point: Option<&'a Play>,
+	pub top_performers: Vec<&'a TopPerformer>,
+	pub streaks: Vec<Streak<'a>>,
+}
+
+/// A run of consecutive successes within the game.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Streak<'a> {
+	/// Consecutive batters retired by a pitcher.
+	BattersRetired { pitcher: &'a NamedPerson, batters: usize },
+	/// Consecutive innings a team scored in.
+	ScoringInnings { side: TeamSide, innings: usize },
+}
+
+impl Streak<'_> {
+	/// Shortest [`Self::BattersRetired`] streak worth mentioning; three perfect innings.
+	pub const MIN_BATTERS_RETIRED: usize = 9;
+	/// Shortest [`Self::ScoringInnings`] streak worth mentioning.
+	pub const MIN_SCORING_INNINGS: usize = 3;
+}
+
+impl<'a> RecapFacts<'a> {
+	/// Gathers the facts of the game in `feed`.
+	#[must_use]
+	pub fn new(feed: &'a LiveFeedResponse) -> Self {
+		let linescore = &feed.live.linescore;
+		let score = linescore.rhe_totals.map(|rhe| rhe.runs);
+		let mut streaks = Self::batters_retired_streaks(feed);
+		streaks.extend(Self::scoring_inning_streaks(feed));
+		Self {
+			game: feed.id,
+			teams: feed.data.teams.as_ref().map(|team| &team.name),
+			score,
+			innings: linescore.innings.len(),
+			scheduled_innings: linescore.scheduled_innings,
+			winner: match score.home.cmp(&score.away) {
+				std::cmp::Ordering::Greater => Some(TeamSide::Home),
+				std::cmp::Ordering::Less => Some(TeamSide::Away),
+				std::cmp::Ordering::Equal => None,
+			},
+			decisions: feed.live.decisions.as_ref(),
+			key_hits: Self::key_hits(feed),
+			turning_point: Self::turning_point(feed),
+			top_performers: feed.live.boxscore.top_performers.iter().flatten().collect(),
+			streaks,
+		}
+	}
+
+	fn key_hits(feed: &'a LiveFeedResponse) -> Vec<&'a Play> {
+		let mut before = HomeAway::new(0, 0);
+		let mut hits = Vec::new();
+		for play in &feed.live.plays {
+			let after = HomeAway::new(play.result.home_score, play.result.away_score);
+			let side = play.about.inning_half.bats();
+			let is_hit = play.result.completed_play_details.as_ref().is_some_and(|details| details.event.is_hit());
+			let (batting_before, fielding_before) = (before.choose(side), before.choose(!side));
+			let (batting_after, fielding_after) = (after.choose(side), after.choose(!side));
+			if is_hit && batting_after > batting_before && batting_before <= fielding_before && batting_after >= fielding_after {
+				hits.push(play);
+			}
+			before = after;
+		}
+		hits
+	}
+
+	fn turning_point(feed: &'a LiveFeedResponse) -> Option<&'a Play> {
+		let plays = &feed.live.plays;
+		plays.iter()
+			.filter_map(|play| Some((play, play.win_probability?.home_win_probability_added.abs())))
+			.max_by(|(_, a), (_, b)| a.total_cmp(b))
+			.map(|(play, _)| play)
+			.or_else(|| plays.iter().filter(|play| play.about.captivating_index > 0).min_by_key(|play| play.about.captivating_index))
+	}
+
+	fn batters_retired_streaks(feed: &'a LiveFeedResponse) -> Vec<Streak<'a>> {
+		// pitcher -> (current streak, longest streak)
+		let mut runs = FxHashMap::<PersonId, (&NamedPerson, usize, usize)>::default();
+		for play in &feed.live.plays {
+			let Some(details) = &play.result.completed_play_details else { continue };
+			if !details.event.is_plate_appearance() {
+				continue;
+			}
+			let (_, current, longest) = runs.entry(play.matchup.pitcher.id).or_insert((&play.matchup.pitcher, 0, 0));
+			*current = if details.is_out { *current + 1 } else { 0 };
+			*longest = (*longest).max(*current);
+		}
+		runs.into_values()
+			.filter(|&(_, _, longest)| longest >= Streak::MIN_BATTERS_RETIRED)
+			.map(|(pitcher, _, batters)| Streak::BattersRetired { pitcher, batters })
+			.collect()
+	}
+
+	fn scoring_inning_streaks(feed: &LiveFeedResponse) -> Vec<Streak<'a>> {
+		[TeamSide::Away, TeamSide::Home].into_iter().filter_map(|side| {
+			let innings = feed.live.linescore.innings.iter()
+				.map(|inning| inning.inning_record.as_ref().choose(side).runs > 0)
+				.fold((0, 0), |(current, longest), scored| if scored { (current + 1, longest.max(current + 1)) } else { (0, longest) })
+				.1;
+			(innings >= Streak::MIN_SCORING_INNINGS).then_some(Streak::ScoringInnings { side, innings })
+		}).collect()
+	}
+
+	/// Renders the facts as a paragraph, one sentence per fact.
+	#[must_use]
+	pub fn render(&self, templates: &RecapTemplates) -> String {
+		let mut sentences = vec![self.render_score(templates)];
+		if let Some(decisions) = self.decisions {
+			for (template, pitcher) in [(&templates.winning_pitcher, &decisions.winner), (&templates.losing_pitcher, &decisions.loser), (&templates.save, &decisions.save)] {
+				sentences.extend(pitcher.as_ref().map(|pitcher| fill(template, &[("pitcher", &pitcher.full_name)])));
+			}
+		}
+		sentences.extend(self.key_hits.iter().map(|play| fill_play(&templates.key_hit, play)));
+		sentences.extend(self.turning_point.filter(|play| !self.key_hits.contains(play)).map(|play| fill_play(&templates.turning_point, play)));
+		sentences.extend(self.top_performers.iter().map(|performer| fill(&templates.top_performer, &[("player", &performer.player.person.full_name), ("game_score", &performer.game_score)])));
+		sentences.extend(self.streaks.iter().map(|streak| self.render_streak(streak, templates)));
+		sentences.retain(|sentence| !sentence.is_empty());
+		sentences.join(" ")
+	}
+
+	fn render_score(&self, templates: &RecapTemplates) -> String {
+		let Some(winner) = self.winner else {
+			return fill(&templates.tie, &[("away", &self.teams.away.full_name), ("home", &self.teams.home.full_name), ("score", &self.score.home)]);
+		};
+		let template = if self.innings > self.scheduled_innings { &templates.final_score_extra_innings } else { &templates.final_score };
+		fill(template, &[
+			("winner", &self.teams.choose(winner).full_name),
+			("loser", &self.teams.choose(!winner).full_name),
+			("winner_score", &self.score.choose(winner)),
+			("loser_score", &self.score.choose(!winner)),
+			("innings", &self.innings),
+		])
+	}
+
+	fn render_streak(&self, streak: &Streak<'_>, templates: &RecapTemplates) -> String {
+		match *streak {
+			Streak::BattersRetired { pitcher, batters } => fill(&templates.batters_retired, &[("pitcher", &pitcher.full_name), ("count", &batters)]),
+			Streak::ScoringInnings { side, innings } => fill(&templates.scoring_innings, &[("team", &self.teams.choose(side).full_name), ("count", &innings)]),
+		}
+	}
+}
+
+/// Sentences of a recap, with `{placeholder}`s substituted by [`RecapFacts::render`]; an empty template omits its sentence.
+///
+/// Every template involving a play accepts `{batter}`, `{pitcher}`, `{description}`, `{half}` (`top` or `bottom`) and `{inning}` (ex: `9th`).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RecapTemplates {
+	/// `{winner}`, `{loser}`, `{winner_score}`, `{loser_score}`, `{innings}`
+	pub final_score: String,
+	/// Same placeholders as [`Self::final_score`], used when the game went to extra innings.
+	pub final_score_extra_innings: String,
+	/// `{away}`, `{home}`, `{score}`
+	pub tie: String,
+	/// `{pitcher}`
+	pub winning_pitcher: String,
+	/// `{pitcher}`
+	pub losing_pitcher: String,
+	/// `{pitcher}`
+	pub save: String,
+	/// A play from [`RecapFacts::key_hits`].
+	pub key_hit: String,
+	/// [`RecapFacts::turning_point`], omitted if it's also a key hit.
+	pub turning_point: String,
+	/// `{player}`, `{game_score}`
+	pub top_performer: String,
+	/// `{pitcher}`, `{count}`
+	pub batters_retired: String,
+	/// `{team}`, `{count}`
+	pub scoring_innings: String,
+}
+
+impl Default for RecapTemplates {
+	fn default() -> Self {
+		Self {
+			final_score: "The {winner} beat the {loser} {winner_score}-{loser_score}.".to_owned(),
+			final_score_extra_innings: "The {winner} beat the {loser} {winner_score}-{loser_score} in {innings} innings.".to_owned(),
+			tie: "The {away} and the {home} are tied {score}-{score}.".to_owned(),
+			winning_pitcher: "{pitcher} earned the win.".to_owned(),
+			losing_pitcher: "{pitcher} took the loss.".to_owned(),
+			save: "{pitcher} recorded the save.".to_owned(),
+			key_hit: "In the {half} of the {inning}: {description}".to_owned(),
+			turning_point: "The turning point came in the {half} of the {inning}: {description}".to_owned(),
+			top_performer: "{player} posted a game score of {game_score}.".to_owned(),
+			batters_retired: "{pitcher} retired {count} consecutive batters.".to_owned(),
+			scoring_innings: "The {team} scored in {count} consecutive innings.".to_owned(),
+		}
+	}
+}
+
+fn fill_play(template: &str, play: &Play) -> String {
+	let half = match play.about.inning_half {
+		InningHalf::Top => "top",
+		InningHalf::Bottom => "bottom",
+	};
+	let description = play.result.completed_play_details.as_ref().map_or("", |details| details.description.as_str());
+	fill(template, &[
+		("batter", &play.matchup.batter.full_name),
+		("pitcher", &play.matchup.pitcher.full_name),
+		("description", &description),
+		("half", &half),
+		("inning", &play.about.inning),
+	])
+}
+
+fn fill(template: &str, values: &[(&str, &dyn Display)]) -> String {
+	values.iter().fold(template.to_owned(), |text, (key, value)| text.replace(&format!("{{{key}}}"), &value.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::TeamSide;
+	use crate::game::{LiveFeedRequest, RecapFacts, RecapTemplates};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_recap() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let facts = RecapFacts::new(&feed);
+		assert_eq!(facts.winner, Some(TeamSide::Away));
+		assert_eq!((facts.score.away, facts.score.home), (5, 4));
+		assert!(facts.innings > facts.scheduled_innings);
+		assert!(facts.turning_point.is_some());
+
+		let templates = RecapTemplates { save: String::new(), ..RecapTemplates::default() };
+		let recap = facts.render(&templates);
+		assert!(recap.starts_with("The Los Angeles Dodgers beat the Toronto Blue Jays 5-4 in 11 innings."), "{recap}");
+		assert!(!recap.contains("recorded the save"));
+	}
+}
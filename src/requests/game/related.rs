@@ -0,0 +1,190 @@
+//! Games related to a game; the original and resumed portions of a suspended game, and the other game of a doubleheader.
+
+use chrono::{NaiveDate, TimeDelta};
+use fxhash::FxHashSet;
+
+use crate::{game::{GameId, LiveFeedRequest, LiveFeedResponse}, request::{self, RequestURLBuilderExt}, schedule::{ScheduleGame, ScheduleRequest}};
+
+/// The schedule entries related to a game, see [`Self::for_game`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct RelatedGames {
+	pub game: GameId,
+	/// Each date the game was played on, in order; more than one if the game was suspended and resumed on a later date.
+	pub portions: Vec<ScheduleGame<()>>,
+	/// The other game of the doubleheader, if the game is part of one.
+	pub double_header: Option<ScheduleGame<()>>,
+}
+
+impl RelatedGames {
+	/// Finds the portions and doubleheader sibling of `game`.
+	///
+	/// # Errors
+	/// See variants of [`request::Error`]
+	pub async fn for_game(game: impl Into<GameId>) -> Result<Self, request::Error> {
+		let feed = LiveFeedRequest::builder().id(game).build_and_get().await?;
+		Self::for_feed(&feed).await
+	}
+
+	/// [`Self::for_game`] for an already fetched feed.
+	///
+	/// # Errors
+	/// See variants of [`request::Error`]
+	pub async fn for_feed(feed: &LiveFeedResponse) -> Result<Self, request::Error> {
+		let datetime = &feed.data.datetime;
+		let sport_id = feed.data.teams.home.sport;
+		let mut dates = vec![datetime.original_date, datetime.official_date];
+		if let Some(resumed) = &datetime.resumed {
+			dates.extend([resumed.resumed_datetime.date_naive(), resumed.resumed_from_datetime.date_naive()]);
+		}
+		// UTC dates can be a day off from the local dates the schedule uses.
+		let start = dates.iter().min().copied().unwrap_or(datetime.official_date) - TimeDelta::days(1);
+		let end = dates.iter().max().copied().unwrap_or(datetime.official_date) + TimeDelta::days(1);
+
+		let schedule = ScheduleRequest::<()>::builder().game_ids(vec![feed.id]).date_range(start..=end).sport_id(sport_id).build_and_get().await?;
+		// keyed by the date the schedule lists each portion under, since both portions can share an official date.
+		let mut portions = schedule.dates.into_iter()
+			.flat_map(|date| date.games.into_iter().filter(|game| game.game_id == feed.id).map(move |game| (date.date, game)))
+			.collect::<Vec<_>>();
+		portions.sort_by_key(|(date, _)| *date);
+		portions.dedup_by_key(|(date, _)| *date);
+		let portions = portions.into_iter().map(|(_, game)| game).collect();
+
+		let double_header = if feed.data.double_header.is_double_header() {
+			Self::double_header_sibling(feed, datetime.official_date).await?
+		} else {
+			None
+		};
+
+		Ok(Self { game: feed.id, portions, double_header })
+	}
+
+	async fn double_header_sibling(feed: &LiveFeedResponse, date: NaiveDate) -> Result<Option<ScheduleGame<()>>, request::Error> {
+		let schedule = ScheduleRequest::<()>::builder().team_id(feed.data.teams.home.id).date(date).sport_id(feed.data.teams.home.sport).build_and_get().await?;
+		Ok(schedule.dates.into_iter().flat_map(|date| date.games).find(|game| game.game_id != feed.id && game.double_header.is_double_header()))
+	}
+
+	/// Whether the game was suspended and resumed on a later date.
+	#[must_use]
+	pub const fn is_suspended(&self) -> bool {
+		self.portions.len() > 1
+	}
+
+	/// The portion of the game before it was suspended, or the whole game.
+	#[must_use]
+	pub fn original(&self) -> Option<&ScheduleGame<()>> {
+		self.portions.first()
+	}
+
+	/// The portion of the game after it was resumed, `None` if it wasn't suspended.
+	#[must_use]
+	pub fn resumed(&self) -> Option<&ScheduleGame<()>> {
+		self.portions.last().filter(|_| self.is_suspended())
+	}
+}
+
+impl LiveFeedResponse {
+	/// Merges the feed of a suspended game's original portion (`self`) with that of its resumed portion into one continuous play-by-play.
+	///
+	/// Everything but the plays is taken from `resumed`, the more recent of the two.
+	/// If both feeds are of the same [`GameId`], plays are matched by at bat index; otherwise the resumed plays are renumbered to follow the original ones.
+	#[must_use]
+	pub fn merge_resumed(self, mut resumed: Self) -> Self {
+		let mut plays = self.live.plays.into_plays();
+		if self.id == resumed.id {
+			let known = resumed.live.plays.iter().map(|play| play.about.at_bat_idx).collect::<FxHashSet<_>>();
+			plays.retain(|play| !known.contains(&play.about.at_bat_idx));
+		} else {
+			let offset = plays.iter().map(|play| play.about.at_bat_idx + 1).max().unwrap_or(0);
+			if let Some(current_play) = &mut resumed.live.plays.current_play {
+				current_play.about.at_bat_idx += offset;
+			}
+			// SAFETY: the caches are rebuilt below
+			for play in unsafe { resumed.live.plays.plays_mut() } {
+				play.about.at_bat_idx += offset;
+			}
+		}
+		// SAFETY: the caches are rebuilt below
+		let resumed_plays = unsafe { resumed.live.plays.plays_mut() };
+		plays.append(resumed_plays);
+		plays.sort_by_key(|play| play.about.at_bat_idx);
+		*resumed_plays = plays;
+		resumed.live.plays.rebuild_indices();
+		resumed
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+	use fxhash::{FxHashMap, FxHashSet};
+
+	use crate::game::{DoubleHeaderKind, GameId, LiveFeedRequest, LiveFeedResponse, RelatedGames};
+	use crate::request::RequestURLBuilderExt;
+	use crate::schedule::{ScheduleDate, ScheduleRequest};
+	use crate::sport::SportId;
+
+	/// The 2024 MLB regular season.
+	async fn schedule_2024() -> Vec<ScheduleDate<()>> {
+		let range = NaiveDate::from_ymd_opt(2024, 3, 28).unwrap()..=NaiveDate::from_ymd_opt(2024, 9, 30).unwrap();
+		ScheduleRequest::<()>::builder().date_range(range).sport_id(SportId::MLB).build_and_get().await.unwrap().dates
+	}
+
+	#[tokio::test]
+	async fn suspended_2024_related() {
+		// a suspended game is listed on both the day it started and the day it was resumed.
+		let mut dates = FxHashMap::<GameId, FxHashSet<NaiveDate>>::default();
+		for date in schedule_2024().await {
+			for game in date.games {
+				dates.entry(game.game_id).or_default().insert(date.date);
+			}
+		}
+		let game = dates.into_iter().filter(|(_, dates)| dates.len() > 1).map(|(game, _)| game).min_by_key(|game| **game).expect("Expected a suspended game in 2024");
+
+		let related = RelatedGames::for_game(game).await.unwrap();
+		assert!(related.is_suspended());
+		let (original, resumed) = (related.original().unwrap(), related.resumed().unwrap());
+		assert_eq!(related.portions.len(), 2);
+		assert_eq!(original.game_id, resumed.game_id);
+		assert!(original.game_date < resumed.game_date);
+	}
+
+	#[tokio::test]
+	async fn double_header_2024_related() {
+		let game = schedule_2024().await.into_iter()
+			.flat_map(|date| date.games)
+			.find(|game| game.double_header == DoubleHeaderKind::FirstGame)
+			.expect("Expected a doubleheader in 2024");
+
+		let related = RelatedGames::for_game(game.game_id).await.unwrap();
+		let sibling = related.double_header.expect("Expected the second game of the doubleheader");
+		assert_ne!(sibling.game_id, game.game_id);
+		assert_eq!(sibling.double_header, DoubleHeaderKind::SecondGame);
+		assert_eq!(sibling.official_date, game.official_date);
+	}
+
+	#[tokio::test]
+	async fn ws_gm7_2025_related() {
+		let related = RelatedGames::for_game(813_024).await.unwrap();
+		assert!(!related.is_suspended());
+		assert!(related.double_header.is_none());
+		assert_eq!(related.original().map(|game| game.game_id), Some(related.game));
+	}
+
+	#[tokio::test]
+	async fn ws_gm7_2025_merge_resumed() {
+		let request = LiveFeedRequest::builder().id(813_024).build();
+		let json = crate::request::get::<serde_json::Value>(request.to_string()).await.unwrap();
+		let full = serde_json::from_value::<LiveFeedResponse>(json.clone()).unwrap();
+		let half = full.live.plays.len() / 2;
+		let (mut original, mut resumed) = (json.clone(), json);
+		original["liveData"]["plays"]["allPlays"].as_array_mut().unwrap().truncate(half);
+		resumed["liveData"]["plays"]["allPlays"].as_array_mut().unwrap().drain(..half);
+		let original = serde_json::from_value::<LiveFeedResponse>(original).unwrap();
+		let resumed = serde_json::from_value::<LiveFeedResponse>(resumed).unwrap();
+
+		let merged = original.merge_resumed(resumed);
+		assert!(merged.live.plays.iter().map(|play| play.about.at_bat_idx).eq(full.live.plays.iter().map(|play| play.about.at_bat_idx)));
+		assert_eq!(merged.live.plays.scoring_plays().count(), full.live.plays.scoring_plays().count());
+		assert_eq!(merged.live.plays.by_inning().count(), full.live.plays.by_inning().count());
+	}
+}
@@ -0,0 +1,545 @@
+//! Export of a game as a [Retrosheet event file](https://www.retrosheet.org/eventfile.htm).
+//!
+//! Produces the `id`, `version`, `info`, `start`, `play`, `sub`, `com` and `data` records for a single game.
+//! Common plays (hits, walks, strikeouts, fielded outs, double plays, fielder's choices, errors, steals, pickoffs, wild pitches, etc.) are translated into Retrosheet's event syntax;
+//! rarer plays fall back to their closest generic form, so this is intended for research pipelines rather than as a replacement for the official Retrosheet files.
+
+use std::fmt::{Display, Formatter};
+
+use fxhash::FxHashMap;
+
+use crate::{ExternalReference, HomeAway, TeamSide, game::{AtBatCount, Base, DoubleHeaderKind, InningHalf, LiveFeedResponse, Play, PlayEvent, PlayWindow, RunnerData, SkyCondition, TeamWithGameData}, meta::{EventType, HitTrajectory, NamedPosition}, person::PersonId, team::{Team, TeamId}, venue::VenueId};
+
+/// Mapping from MLB IDs to Retrosheet IDs.
+///
+/// Players (and umpires, which share the same ID space) and teams without a Retrosheet ID fall back to their MLB ID (players) or abbreviation (teams),
+/// which [`Self::person_id`] understands so that exported files can be mapped back.
+/// Venues without a Retrosheet park code fall back to their MLB ID, so the `info,site` record is only a valid park code once mapped.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RetrosheetIds {
+	players: FxHashMap<PersonId, String>,
+	teams: FxHashMap<TeamId, String>,
+	venues: FxHashMap<VenueId, String>,
+}
+
+impl RetrosheetIds {
+	/// [`ExternalReference::xref_type`] of Retrosheet IDs.
+	pub const XREF_TYPE: &'static str = "retrosheet";
+
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	pub fn insert_player(&mut self, id: PersonId, retrosheet_id: impl Into<String>) {
+		self.players.insert(id, retrosheet_id.into());
+	}
+
+	pub fn insert_team(&mut self, id: TeamId, retrosheet_id: impl Into<String>) {
+		self.teams.insert(id, retrosheet_id.into());
+	}
+
+	pub fn insert_venue(&mut self, id: VenueId, park_code: impl Into<String>) {
+		self.venues.insert(id, park_code.into());
+	}
+
+	/// Adds the player's Retrosheet ID from their `xrefIds` hydration, returns whether one was present.
+	pub fn insert_player_references(&mut self, id: PersonId, references: &[ExternalReference]) -> bool {
+		let Some(reference) = references.iter().find(|reference| reference.xref_type == Self::XREF_TYPE) else { return false };
+		self.insert_player(id, reference.id.clone());
+		true
+	}
+
+	/// Adds the team's Retrosheet ID from their `xrefIds` hydration, returns whether one was present.
+	pub fn insert_team_references(&mut self, id: TeamId, references: &[ExternalReference]) -> bool {
+		let Some(reference) = references.iter().find(|reference| reference.xref_type == Self::XREF_TYPE) else { return false };
+		self.insert_team(id, reference.id.clone());
+		true
+	}
+
+	/// Retrosheet ID of the player or umpire, or their MLB ID if unmapped.
+	#[must_use]
+	pub fn player(&self, id: PersonId) -> String {
+		self.players.get(&id).cloned().unwrap_or_else(|| id.to_string())
+	}
+
+	/// Retrosheet code of the team, or their abbreviation if unmapped.
+	#[must_use]
+	pub fn team(&self, team: &Team<()>) -> String {
+		self.teams.get(&team.id).cloned().unwrap_or_else(|| team.name.abbreviation.clone())
+	}
+
+	/// Retrosheet park code of the venue (ex: `TOR02`), or its MLB ID if unmapped.
+	#[must_use]
+	pub fn venue(&self, id: VenueId) -> String {
+		self.venues.get(&id).cloned().unwrap_or_else(|| id.to_string())
+	}
+
+	/// Maps an ID from an exported file back to the player, the reverse of [`Self::player`].
+	#[must_use]
+	pub fn person_id(&self, retrosheet_id: &str) -> Option<PersonId> {
+		self.players.iter()
+			.find_map(|(&id, mapped)| (mapped == retrosheet_id).then_some(id))
+			.or_else(|| retrosheet_id.parse::<u32>().ok().map(PersonId::from))
+	}
+}
+
+/// A [`Display`]able Retrosheet event file, see [`LiveFeedResponse::retrosheet`].
+#[derive(Debug, Copy, Clone)]
+pub struct RetrosheetEventFile<'a> {
+	feed: &'a LiveFeedResponse,
+	ids: &'a RetrosheetIds,
+}
+
+impl LiveFeedResponse {
+	/// Exports this game as Retrosheet event file records.
+	///
+	/// ## Examples
+	/// ```no_run
+	/// let feed: LiveFeedResponse = ...;
+	///
+	/// let mut ids = RetrosheetIds::new();
+	/// ids.insert_player(660_271.into(), "ohtas001");
+	/// std::fs::write("2025TOR.EVA", feed.retrosheet(&ids).to_string())?;
+	/// ```
+	#[must_use]
+	pub const fn retrosheet<'a>(&'a self, ids: &'a RetrosheetIds) -> RetrosheetEventFile<'a> {
+		RetrosheetEventFile { feed: self, ids }
+	}
+}
+
+impl Display for RetrosheetEventFile<'_> {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		self.write_info(f)?;
+		self.write_starters(f)?;
+		for play in &self.feed.live.plays {
+			self.write_play(f, play)?;
+		}
+		self.write_earned_runs(f)
+	}
+}
+
+impl RetrosheetEventFile<'_> {
+	fn write_info(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let data = &self.feed.data;
+		let number = match data.double_header {
+			DoubleHeaderKind::Not => 0,
+			DoubleHeaderKind::FirstGame => 1,
+			DoubleHeaderKind::SecondGame => 2,
+		};
+		writeln!(f, "id,{}{}{number}", self.ids.team(&data.teams.home), data.datetime.official_date.format("%Y%m%d"))?;
+		writeln!(f, "version,2")?;
+		writeln!(f, "info,visteam,{}", self.ids.team(&data.teams.away))?;
+		writeln!(f, "info,hometeam,{}", self.ids.team(&data.teams.home))?;
+		writeln!(f, "info,site,{}", self.ids.venue(data.venue.id))?;
+		writeln!(f, "info,date,{}", data.datetime.official_date.format("%Y/%m/%d"))?;
+		writeln!(f, "info,number,{number}")?;
+		writeln!(f, "info,starttime,{}{:?}", data.datetime.time.format("%-I:%M"), data.datetime.ampm)?;
+		writeln!(f, "info,daynight,{}", format!("{:?}", data.datetime.sky).to_lowercase())?;
+		writeln!(f, "info,usedh,{}", self.uses_dh())?;
+		for official in &self.feed.live.boxscore.officials {
+			writeln!(f, "info,ump{},{}", official.official_type.abbreviation().to_lowercase(), self.ids.player(official.official.id))?;
+		}
+		self.write_conditions(f)?;
+		self.write_decisions(f)
+	}
+
+	fn write_conditions(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let data = &self.feed.data;
+		if let Some(temp) = data.weather.temp {
+			writeln!(f, "info,temp,{:.0}", temp.get::<uom::si::thermodynamic_temperature::degree_fahrenheit>())?;
+		}
+		if let Some(wind) = &data.weather.wind {
+			writeln!(f, "info,winddir,{}", wind_direction(&wind.direction))?;
+			if let Some(speed) = wind.speed {
+				writeln!(f, "info,windspeed,{:.0}", speed.get::<uom::si::velocity::mile_per_hour>())?;
+			}
+		}
+		if let Some(condition) = &data.weather.condition {
+			writeln!(f, "info,sky,{}", sky(condition))?;
+		}
+		if let Some(duration) = data.info.game_duration {
+			writeln!(f, "info,timeofgame,{duration}")?;
+		}
+		if let Some(attendance) = data.info.attendance {
+			writeln!(f, "info,attendance,{attendance}")?;
+		}
+		Ok(())
+	}
+
+	fn write_decisions(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let Some(decisions) = &self.feed.live.decisions else { return Ok(()) };
+		for (record, person) in [("wp", &decisions.winner), ("lp", &decisions.loser), ("save", &decisions.save)] {
+			if let Some(person) = person {
+				writeln!(f, "info,{record},{}", self.ids.player(person.id))?;
+			}
+		}
+		Ok(())
+	}
+
+	fn uses_dh(&self) -> bool {
+		self.feed.live.boxscore.teams.as_ref().combine(|home, away| {
+			[home, away].into_iter()
+				.flat_map(|team| team.players.values())
+				.any(|player| player.batting_order.is_some_and(|order| order.minor == 0) && player.all_positions.first().is_some_and(|position| position.code.as_str() == "10"))
+		})
+	}
+
+	fn write_starters(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let teams = self.feed.live.boxscore.teams.as_ref();
+		let uses_dh = self.uses_dh();
+		for (flag, team) in [(0, teams.away), (1, teams.home)] {
+			let lineup = team.batting_order.as_ref().map_or(&[][..], |order| &order[..]);
+			for (idx, &id) in lineup.iter().enumerate() {
+				let position = team.players.get(&id).and_then(|player| player.all_positions.first()).map_or("0", |position| position.code.as_str());
+				writeln!(f, "start,{},\"{}\",{flag},{},{position}", self.ids.player(id), self.name(id, team), idx + 1)?;
+			}
+			// with a DH the pitcher always gets their own line, even if they also start at DH.
+			if let Some(&pitcher) = team.pitchers.first() && (uses_dh || !lineup.contains(&pitcher)) {
+				writeln!(f, "start,{},\"{}\",{flag},0,1", self.ids.player(pitcher), self.name(pitcher, team))?;
+			}
+		}
+		Ok(())
+	}
+
+	fn name(&self, id: PersonId, team: &TeamWithGameData) -> String {
+		self.feed.data.players.get(&id).map(|player| player.full_name.clone())
+			.or_else(|| team.players.get(&id).map(|player| player.person.full_name.clone()))
+			.unwrap_or_default()
+			.replace('"', "'")
+	}
+
+	fn write_play(&self, f: &mut Formatter<'_>, play: &Play) -> std::fmt::Result {
+		let mut record = PlayRecord { file: self, play, pitches: String::new(), count: AtBatCount::default(), latest_count: AtBatCount::default() };
+		for (idx, event) in play.play_events.iter().enumerate() {
+			record.write_event(f, idx, event)?;
+		}
+		let Some(completed) = &play.result.completed_play_details else { return Ok(()) };
+		if !completed.event.is_plate_appearance() {
+			return Ok(());
+		}
+		let runners = play.runners.iter()
+			.filter(|runner| runner.details.play_event_index.and_then(|idx| play.play_events.get(idx)).is_none_or(|event| !is_base_running_action(event)))
+			.collect::<Vec<_>>();
+		record.write(f, record.count, &format!("{}{}", batter_event(play, completed.event, &runners), advances(play, completed.event, &runners)))
+	}
+
+	fn write_earned_runs(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		let lines = self.feed.live.plays.lines(PlayWindow::all());
+		let teams = self.feed.live.boxscore.teams.as_ref();
+		for pitcher in teams.away.pitchers.iter().chain(&teams.home.pitchers) {
+			let earned_runs = lines.pitching.get(pitcher).map_or(0, |line| line.earned_runs);
+			writeln!(f, "data,er,{},{earned_runs}", self.ids.player(*pitcher))?;
+		}
+		Ok(())
+	}
+}
+
+/// A batter's plate appearance being written out, tracking the pitch sequence as it goes.
+struct PlayRecord<'a, 'b> {
+	file: &'a RetrosheetEventFile<'b>,
+	play: &'a Play,
+	pitches: String,
+	/// Count before the latest pitch
+	count: AtBatCount,
+	/// Count after the latest pitch, as the feed reports it on each pitch; the count for steals and other plays between pitches.
+	latest_count: AtBatCount,
+}
+
+impl PlayRecord<'_, '_> {
+	fn write_event(&mut self, f: &mut Formatter<'_>, idx: usize, event: &PlayEvent) -> std::fmt::Result {
+		match event {
+			PlayEvent::Pitch { details, common, .. } => {
+				if details.runner_going {
+					self.pitches.push('>');
+				}
+				self.pitches.push(pitch_char(details.call.as_str()));
+				self.advance_count(common.count);
+				Ok(())
+			},
+			PlayEvent::Pickoff { details, common, .. } => {
+				if let Some(base) = common.base {
+					self.pitches.push_str(if details.from_catcher { "+" } else { "" });
+					self.pitches.push(base_char(Some(base)));
+				}
+				Ok(())
+			},
+			PlayEvent::NoPitch { common, .. } => {
+				self.pitches.push('N');
+				self.advance_count(common.count);
+				Ok(())
+			},
+			PlayEvent::Action { details, common, .. } if common.is_substitution => self.file.write_substitution(f, self.play, details.event, common.player, common.position.as_ref(), common.batting_order_index.map_or(0, |order| order.major)),
+			PlayEvent::Action { details, .. } if is_base_running_action(event) => self.write_base_running(f, idx, details.event),
+			PlayEvent::Action { details, .. } => writeln!(f, "com,\"{}\"", details.description.replace('"', "'")),
+			PlayEvent::Stepoff { .. } => Ok(()),
+		}
+	}
+
+	fn write_base_running(&mut self, f: &mut Formatter<'_>, idx: usize, event: EventType) -> std::fmt::Result {
+		let runners = self.play.runners.iter().filter(|runner| runner.details.play_event_index == Some(idx)).collect::<Vec<_>>();
+		self.write(f, self.latest_count, &base_running_event(event, &runners))?;
+		self.pitches.push('.');
+		Ok(())
+	}
+
+	/// Moves on to the next pitch, `count` being the count after it.
+	const fn advance_count(&mut self, count: AtBatCount) {
+		self.count = self.latest_count;
+		self.latest_count = AtBatCount { outs: 0, ..count };
+	}
+
+	fn write(&self, f: &mut Formatter<'_>, count: AtBatCount, event: &str) -> std::fmt::Result {
+		let about = &self.play.about;
+		let team = u8::from(about.inning_half == InningHalf::Bottom);
+		writeln!(f, "play,{},{team},{},{}{},{},{event}", about.inning, self.file.ids.player(self.play.matchup.batter.id), count.balls, count.strikes, self.pitches)
+	}
+}
+
+impl RetrosheetEventFile<'_> {
+	fn write_substitution(&self, f: &mut Formatter<'_>, play: &Play, event: EventType, player: Option<PersonId>, position: Option<&NamedPosition>, batting_order: usize) -> std::fmt::Result {
+		let Some(player) = player else { return Ok(()) };
+		if event == EventType::UmpireSubstitution {
+			return Ok(());
+		}
+		let side = if event == EventType::OffensiveSubstitution { play.about.inning_half.bats() } else { play.about.inning_half.pitches() };
+		let flag = u8::from(side == TeamSide::Home);
+		let team = HomeAway::new(&self.feed.live.boxscore.teams.home, &self.feed.live.boxscore.teams.away).choose(side);
+		let position = position.map_or("0", |position| position.code.as_str());
+		writeln!(f, "sub,{},\"{}\",{flag},{batting_order},{position}", self.ids.player(player), self.name(player, team))
+	}
+}
+
+const fn is_base_running_action(event: &PlayEvent) -> bool {
+	matches!(event, PlayEvent::Action { details, .. } if details.event.is_base_running_event())
+}
+
+/// The batter's event, before the `.` runner advances.
+fn batter_event(play: &Play, event: EventType, runners: &[&RunnerData]) -> String {
+	let batter = play.matchup.batter.id;
+	let first_fielder = runners.iter().flat_map(|runner| &runner.credits).next().map_or("", |credit| credit.position.code.as_str());
+	let error_fielder = runners.iter().flat_map(|runner| &runner.credits).find(|credit| credit.credit.is_error()).map_or("", |credit| credit.position.code.as_str());
+	match event {
+		EventType::Single => format!("S{first_fielder}"),
+		EventType::Double => format!("D{first_fielder}"),
+		EventType::Triple => format!("T{first_fielder}"),
+		EventType::HomeRun => "HR".to_owned(),
+		EventType::Walk => "W".to_owned(),
+		EventType::IntentionalWalk => "IW".to_owned(),
+		EventType::HitByPitch => "HP".to_owned(),
+		EventType::CatchersInterference => "C/E2".to_owned(),
+		EventType::Strikeout => "K".to_owned(),
+		EventType::StrikeoutDoublePlay => "K/DP".to_owned(),
+		EventType::StrikeoutTriplePlay => "K/TP".to_owned(),
+		EventType::FieldError => format!("E{error_fielder}{}", trajectory_modifier(play)),
+		EventType::FieldersChoice => format!("FC{first_fielder}{}", trajectory_modifier(play)),
+		EventType::SacrificeBunt => format!("{}/SH", out_chain(runners, batter)),
+		EventType::SacrificeFly => format!("{}/SF", out_chain(runners, batter)),
+		EventType::GroundedIntoDoublePlay => format!("{}/GDP", out_chain(runners, batter)),
+		EventType::DoublePlay | EventType::SacrificeFlyDoublePlay | EventType::SacrificeBuntDoublePlay => format!("{}/DP{}", out_chain(runners, batter), trajectory_modifier(play)),
+		EventType::GroundedIntoTriplePlay | EventType::TriplePlay => format!("{}/TP{}", out_chain(runners, batter), trajectory_modifier(play)),
+		EventType::ForceOut | EventType::FieldersChoiceFieldOut => format!("{}/FO{}", out_chain(runners, batter), trajectory_modifier(play)),
+		_ => format!("{}{}", out_chain(runners, batter), trajectory_modifier(play)),
+	}
+}
+
+/// Event for a play not involving the batter (steals, pickoffs, wild pitches, etc.)
+fn base_running_event(event: EventType, runners: &[&RunnerData]) -> String {
+	let outs = runners.iter().filter(|runner| runner.movement.is_out).map(|runner| format!("{}({})", base_char(runner.movement.out_base), fielders(runner))).collect::<Vec<_>>().concat();
+	let steals = runners.iter().filter(|runner| !runner.movement.is_out).map(|runner| format!("SB{}", base_char(runner.movement.end_base))).collect::<Vec<_>>().join(";");
+	let base = match event {
+		EventType::StolenBase | EventType::StolenBase2B | EventType::StolenBase3B | EventType::StolenBaseHome => steals,
+		EventType::CaughtStealing | EventType::CaughtStealing2B | EventType::CaughtStealing3B | EventType::CaughtStealingHome | EventType::CaughtStealingDoublePlay => format!("CS{outs}"),
+		EventType::Pickoff1B | EventType::Pickoff2B | EventType::Pickoff3B => format!("PO{}", runners.iter().find(|runner| runner.movement.is_out).map(|runner| format!("{}({})", base_char(runner.movement.start_base), fielders(runner))).unwrap_or_default()),
+		EventType::PickoffCaughtStealing2B | EventType::PickoffCaughtStealing3B | EventType::PickoffCaughtStealingHome => format!("POCS{outs}"),
+		EventType::WildPitch => "WP".to_owned(),
+		EventType::PassedBall => "PB".to_owned(),
+		EventType::Balk => "BK".to_owned(),
+		EventType::DefensiveIndifference => "DI".to_owned(),
+		_ => "OA".to_owned(),
+	};
+	let advances = runners.iter()
+		.filter(|runner| !runner.movement.is_out && runner.movement.start_base != runner.movement.end_base)
+		.map(|runner| advance(runner))
+		.collect::<Vec<_>>();
+	if advances.is_empty() { base } else { format!("{base}.{}", advances.join(";")) }
+}
+
+/// Outs on the play as `64(1)3`; fielders handling the ball, with each non-batter runner's starting base after their out.
+fn out_chain(runners: &[&RunnerData], batter: PersonId) -> String {
+	let mut outs = runners.iter().filter(|runner| runner.movement.is_out).collect::<Vec<_>>();
+	outs.sort_by_key(|runner| runner.movement.out_number);
+	let mut chain = String::new();
+	for runner in outs {
+		let fielders = fielders(runner);
+		let fielders = match (chain.chars().last(), fielders.chars().next()) {
+			(Some(last), Some(first)) if last == first => &fielders[first.len_utf8()..],
+			_ => &fielders,
+		};
+		chain.push_str(fielders);
+		if runner.details.runner.id != batter {
+			chain.push('(');
+			chain.push(base_char(runner.movement.start_base));
+			chain.push(')');
+		}
+	}
+	chain
+}
+
+/// Fielders credited on a runner's out, `643`.
+fn fielders(runner: &RunnerData) -> String {
+	let mut fielders = String::new();
+	for credit in runner.credits.iter().filter(|credit| credit.credit.is_assist_or_putout()) {
+		if !fielders.ends_with(credit.position.code.as_str()) {
+			fielders.push_str(credit.position.code.as_str());
+		}
+	}
+	fielders
+}
+
+/// Runner advances after the batter's event, `.1-3;B-2`.
+fn advances(play: &Play, event: EventType, runners: &[&RunnerData]) -> String {
+	let batter = play.matchup.batter.id;
+	let implied_batter_base = match event {
+		EventType::Double => Some(Base::Second),
+		EventType::Triple => Some(Base::Third),
+		EventType::HomeRun => Some(Base::Home),
+		event if event.is_out() && !matches!(event, EventType::FieldersChoice | EventType::FieldersChoiceFieldOut | EventType::ForceOut | EventType::FieldError) => None,
+		_ => Some(Base::First),
+	};
+	let in_chain = matches!(event, EventType::GroundedIntoDoublePlay | EventType::DoublePlay | EventType::GroundedIntoTriplePlay | EventType::TriplePlay | EventType::ForceOut | EventType::FieldersChoiceFieldOut | EventType::SacrificeFlyDoublePlay | EventType::SacrificeBuntDoublePlay);
+
+	let mut by_runner: Vec<(PersonId, RunnerData)> = Vec::new();
+	for runner in runners {
+		match by_runner.iter_mut().find(|(id, _)| *id == runner.details.runner.id) {
+			// merge consecutive movement segments of the same runner into one.
+			Some((_, merged)) => {
+				merged.movement.end_base = runner.movement.end_base;
+				merged.movement.is_out |= runner.movement.is_out;
+				merged.movement.out_base = merged.movement.out_base.or(runner.movement.out_base);
+				merged.credits.extend(runner.credits.iter().cloned());
+				merged.details.is_earned = runner.details.is_earned;
+			},
+			None => by_runner.push((runner.details.runner.id, (*runner).clone())),
+		}
+	}
+
+	let advances = by_runner.iter()
+		.filter(|(id, runner)| if *id == batter { !runner.movement.is_out && runner.movement.end_base != implied_batter_base } else { !(runner.movement.is_out && in_chain) && (runner.movement.is_out || runner.movement.start_base != runner.movement.end_base) })
+		.map(|(_, runner)| advance(runner))
+		.collect::<Vec<_>>();
+	if advances.is_empty() { String::new() } else { format!(".{}", advances.join(";")) }
+}
+
+fn advance(runner: &RunnerData) -> String {
+	let start = base_char(runner.movement.start_base);
+	if runner.movement.is_out {
+		format!("{start}X{}({})", base_char(runner.movement.out_base), fielders(runner))
+	} else if runner.movement.end_base == Some(Base::Home) && !runner.details.is_earned {
+		format!("{start}-H(UR)")
+	} else {
+		format!("{start}-{}", base_char(runner.movement.end_base))
+	}
+}
+
+/// `B` for the batter (no starting base), otherwise the base number or `H`.
+const fn base_char(base: Option<Base>) -> char {
+	match base {
+		None => 'B',
+		Some(Base::First) => '1',
+		Some(Base::Second) => '2',
+		Some(Base::Third) => '3',
+		Some(Base::Home) => 'H',
+	}
+}
+
+fn trajectory_modifier(play: &Play) -> &'static str {
+	let trajectory = play.play_events.iter().rev().find_map(|event| match event {
+		PlayEvent::Pitch { hit_data: Some(hit_data), .. } => hit_data.hit_trajectory,
+		_ => None,
+	});
+	match trajectory {
+		Some(HitTrajectory::GroundBall) => "/G",
+		Some(HitTrajectory::LineDrive) => "/L",
+		Some(HitTrajectory::FlyBall) => "/F",
+		Some(HitTrajectory::Popup) => "/P",
+		Some(HitTrajectory::BuntGrounder) => "/BG",
+		Some(HitTrajectory::BuntPopup) => "/BP",
+		Some(HitTrajectory::BuntLineDrive) => "/BL",
+		_ => "",
+	}
+}
+
+/// Retrosheet's pitch character for an MLB [`PitchCodeId`](crate::meta::PitchCodeId).
+fn pitch_char(code: &str) -> char {
+	match code {
+		"B" | "*B" => 'B',
+		"C" => 'C',
+		"S" | "W" => 'S',
+		"F" => 'F',
+		"T" => 'T',
+		"L" => 'L',
+		"M" => 'M',
+		"O" => 'O',
+		"X" | "D" | "E" => 'X',
+		"H" => 'H',
+		"I" => 'I',
+		"P" => 'P',
+		"Q" => 'Q',
+		"R" => 'R',
+		"V" | "VP" | "AB" => 'V',
+		"A" | "AC" | "K" => 'A',
+		_ => 'U',
+	}
+}
+
+fn wind_direction(direction: &str) -> &'static str {
+	match direction.to_lowercase().replace(' ', "").as_str() {
+		"outtocf" => "tocf",
+		"outtolf" => "tolf",
+		"outtorf" => "torf",
+		"infromcf" => "fromcf",
+		"infromlf" => "fromlf",
+		"infromrf" => "fromrf",
+		"ltor" => "ltor",
+		"rtol" => "rtol",
+		_ => "unknown",
+	}
+}
+
+fn sky(condition: &SkyCondition) -> &'static str {
+	match condition {
+		SkyCondition::Dome | SkyCondition::RoofClosed => "dome",
+		SkyCondition::Sunny | SkyCondition::Clear => "sunny",
+		SkyCondition::Cloudy | SkyCondition::PartlyCloudy => "cloudy",
+		SkyCondition::Overcast => "overcast",
+		SkyCondition::Unknown(condition) if condition.eq_ignore_ascii_case("night") => "night",
+		_ => "unknown",
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{LiveFeedRequest, RetrosheetIds};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_retrosheet() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let mut ids = RetrosheetIds::new();
+		ids.insert_venue(feed.data.venue.id, "TOR02");
+		let file = feed.retrosheet(&ids).to_string();
+		assert!(file.starts_with("id,TOR20251101"), "unexpected id line in {file}");
+		assert!(file.contains("info,visteam,LAD\ninfo,hometeam,TOR\n"));
+		assert!(file.contains("info,date,2025/11/01\n"));
+		assert!(file.contains("info,site,TOR02\n"));
+		assert!(file.lines().any(|line| line.starts_with("play,11,0,")) && file.lines().any(|line| line.starts_with("play,11,1,")));
+		assert_eq!(file.lines().filter(|line| line.starts_with("start,")).count(), 18 + usize::from(file.contains("info,usedh,true")) * 2);
+		for line in file.lines().filter(|line| line.starts_with("play,")) {
+			let count = line.split(',').nth(4).unwrap().as_bytes();
+			assert!(count[0] <= b'3' && count[1] <= b'2', "impossible count in {line}");
+		}
+		let batter = feed.live.plays.first().unwrap().matchup.batter.id;
+		assert_eq!(ids.person_id(&ids.player(batter)), Some(batter));
+	}
+}
@@ -0,0 +1,261 @@
+//! Run expectancy (RE24); the average runs scored through the end of the inning from each of the 24 base-out states, and the run value of each plate appearance.
+
+use fxhash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{HomeAway, game::{BaseOccupancy, Play, Plays}, meta::EventType, person::PersonId};
+
+/// Runners on base and outs, one of the 24 states of a [`RunExpectancyMatrix`] (or the end of the inning).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BaseOutState {
+	pub bases: BaseOccupancy,
+	pub outs: u8,
+}
+
+impl BaseOutState {
+	#[must_use]
+	pub const fn is_inning_over(&self) -> bool {
+		self.outs >= 3
+	}
+}
+
+impl BaseOccupancy {
+	/// Bit index of the base state; first base is 1, second 2, third 4.
+	#[must_use]
+	pub const fn index(&self) -> usize {
+		self.first as usize | (self.second as usize) << 1 | (self.third as usize) << 2
+	}
+
+	/// Inverse of [`Self::index`].
+	#[must_use]
+	pub const fn from_index(index: usize) -> Self {
+		Self { first: index & 1 != 0, second: index & 2 != 0, third: index & 4 != 0 }
+	}
+}
+
+/// Expected runs through the end of the inning from each base-out state.
+///
+/// Built once from a large sample of games (ex: a season) with [`Self::from_plays`], then reused; it can be stored with any serde format.
+///
+/// ## Examples
+/// ```no_run
+/// let season: Vec<LiveFeedResponse> = ...;
+///
+/// let matrix = RunExpectancyMatrix::from_plays(season.iter().map(|feed| &feed.live.plays));
+/// let re24 = matrix.players(season.iter().map(|feed| &feed.live.plays));
+/// for (batter, runs) in &re24.batters {
+///     println!("{batter}: {runs:+.1}");
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunExpectancyMatrix {
+	/// Indexed by outs, then [`BaseOccupancy::index`].
+	pub expected_runs: [[f64; 8]; 3],
+	/// Plate appearances each expectation was sampled from.
+	pub occurrences: [[usize; 8]; 3],
+}
+
+/// A plate appearance and the change in run expectancy over it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlateAppearanceRunValue<'a> {
+	pub play: &'a Play,
+	pub start: BaseOutState,
+	pub end: BaseOutState,
+	/// Runs scored by the batting team during the play.
+	pub runs: usize,
+	/// Runs added by the batting team; `RE(end) - RE(start) + runs`.
+	///
+	/// Includes baserunning during the plate appearance, such as stolen bases.
+	pub re24: f64,
+}
+
+/// Accumulated run value, see [`RunExpectancyMatrix::event_run_values`].
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct RunValue {
+	pub total: f64,
+	pub occurrences: usize,
+}
+
+impl RunValue {
+	/// Mean run value, `None` if there are no occurrences.
+	#[must_use]
+	#[allow(clippy::cast_precision_loss, reason = "far fewer than 2^52 plate appearances")]
+	pub fn average(&self) -> Option<f64> {
+		(self.occurrences > 0).then(|| self.total / self.occurrences as f64)
+	}
+
+	fn record(&mut self, re24: f64) {
+		self.total += re24;
+		self.occurrences += 1;
+	}
+}
+
+/// RE24 totals per player.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PlayerRunValues {
+	/// Runs added at the plate.
+	pub batters: FxHashMap<PersonId, f64>,
+	/// Runs prevented on the mound, the negative of the batters' RE24.
+	pub pitchers: FxHashMap<PersonId, f64>,
+}
+
+impl RunExpectancyMatrix {
+	/// Approximate MLB run expectancy of the 2010s, for when there isn't a sample to build one from.
+	pub const TYPICAL: Self = Self {
+		expected_runs: [
+			[0.481, 0.859, 1.100, 1.437, 1.350, 1.784, 1.964, 2.292],
+			[0.254, 0.509, 0.664, 0.884, 0.950, 1.130, 1.376, 1.541],
+			[0.098, 0.224, 0.319, 0.429, 0.353, 0.478, 0.580, 0.752],
+		],
+		occurrences: [[0; 8]; 3],
+	};
+
+	/// Averages the runs scored through the end of the inning from the start of each plate appearance.
+	///
+	/// Half-innings that didn't reach three outs (walk-offs, games in progress) are excluded, as are incomplete plays.
+	#[must_use]
+	#[allow(clippy::cast_precision_loss, reason = "far fewer than 2^52 runs")]
+	pub fn from_plays<'a>(games: impl IntoIterator<Item = &'a Plays>) -> Self {
+		let mut runs = [[0_usize; 8]; 3];
+		let mut occurrences = [[0_usize; 8]; 3];
+		for plays in games {
+			for transition in transitions(plays) {
+				if let Some(runs_to_end_of_inning) = transition.runs_to_end_of_inning && !transition.start.is_inning_over() {
+					let (outs, bases) = (usize::from(transition.start.outs), transition.start.bases.index());
+					runs[outs][bases] += runs_to_end_of_inning;
+					occurrences[outs][bases] += 1;
+				}
+			}
+		}
+		let expected_runs = std::array::from_fn(|outs| std::array::from_fn(|bases| match occurrences[outs][bases] {
+			0 => 0.0,
+			count => runs[outs][bases] as f64 / count as f64,
+		}));
+		Self { expected_runs, occurrences }
+	}
+
+	/// Expected runs through the end of the inning, `0.0` once it's over.
+	#[must_use]
+	pub fn get(&self, state: BaseOutState) -> f64 {
+		if state.is_inning_over() {
+			0.0
+		} else {
+			self.expected_runs[usize::from(state.outs)][state.bases.index()]
+		}
+	}
+
+	/// RE24 of every complete plate appearance in the game.
+	#[must_use]
+	#[allow(clippy::cast_precision_loss, reason = "far fewer than 2^52 runs")]
+	pub fn plate_appearances<'a>(&self, plays: &'a Plays) -> Vec<PlateAppearanceRunValue<'a>> {
+		transitions(plays).into_iter().map(|transition| PlateAppearanceRunValue {
+			play: transition.play,
+			start: transition.start,
+			end: transition.end,
+			runs: transition.runs,
+			re24: self.get(transition.end) - self.get(transition.start) + transition.runs as f64,
+		}).collect()
+	}
+
+	/// RE24 of each type of play result, such as the run value of a single or a strikeout.
+	#[must_use]
+	pub fn event_run_values<'a>(&self, games: impl IntoIterator<Item = &'a Plays>) -> FxHashMap<EventType, RunValue> {
+		let mut values = FxHashMap::<EventType, RunValue>::default();
+		for plays in games {
+			for plate_appearance in self.plate_appearances(plays) {
+				if let Some(details) = &plate_appearance.play.result.completed_play_details {
+					values.entry(details.event).or_default().record(plate_appearance.re24);
+				}
+			}
+		}
+		values
+	}
+
+	/// RE24 for each batter and pitcher, credited to the batter and pitcher at the end of the plate appearance.
+	#[must_use]
+	pub fn players<'a>(&self, games: impl IntoIterator<Item = &'a Plays>) -> PlayerRunValues {
+		let mut values = PlayerRunValues::default();
+		for plays in games {
+			for plate_appearance in self.plate_appearances(plays) {
+				let matchup = &plate_appearance.play.matchup;
+				*values.batters.entry(matchup.batter.id).or_default() += plate_appearance.re24;
+				*values.pitchers.entry(matchup.pitcher.id).or_default() -= plate_appearance.re24;
+			}
+		}
+		values
+	}
+}
+
+/// A complete plate appearance's base-out states and runs.
+struct Transition<'a> {
+	play: &'a Play,
+	start: BaseOutState,
+	end: BaseOutState,
+	runs: usize,
+	/// Runs from the start of the play through the end of the inning, `None` if the half-inning didn't reach three outs.
+	runs_to_end_of_inning: Option<usize>,
+}
+
+fn transitions(plays: &Plays) -> Vec<Transition<'_>> {
+	let mut transitions = Vec::with_capacity(plays.len());
+	let mut previous: Option<&Play> = None;
+	for play in plays.iter().filter(|play| play.about.is_complete) {
+		let (bases, outs) = play.starting_situation(previous);
+		let side = play.about.inning_half.bats();
+		let score = |play: &Play| HomeAway::new(play.result.home_score, play.result.away_score).choose(side);
+		let end_outs = play.count.outs.min(3);
+		transitions.push(Transition {
+			play,
+			start: BaseOutState { bases, outs },
+			end: BaseOutState { bases: if end_outs >= 3 { BaseOccupancy::default() } else { play.matchup.ending_bases() }, outs: end_outs },
+			runs: score(play).saturating_sub(previous.map_or(0, score)),
+			runs_to_end_of_inning: None,
+		});
+		previous = Some(play);
+	}
+	for half_inning in transitions.chunk_by_mut(|a, b| a.play.about.inning == b.play.about.inning && a.play.about.inning_half == b.play.about.inning_half) {
+		// the next plate appearance's start is the more complete picture of the bases, it accounts for runners moving between plays.
+		for idx in 1..half_inning.len() {
+			half_inning[idx - 1].end = half_inning[idx].start;
+		}
+		if half_inning.last().is_some_and(|transition| transition.end.is_inning_over()) {
+			let mut remaining = 0;
+			for transition in half_inning.iter_mut().rev() {
+				remaining += transition.runs;
+				transition.runs_to_end_of_inning = Some(remaining);
+			}
+		}
+	}
+	transitions
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{BaseOutState, InningHalf, LiveFeedRequest, RunExpectancyMatrix, RunValue};
+	use crate::request::RequestURLBuilderExt;
+
+	#[test]
+	fn run_value_average() {
+		assert_eq!(RunValue::default().average(), None);
+		assert_eq!(RunValue { total: 1.5, occurrences: 3 }.average(), Some(0.5));
+	}
+
+	#[tokio::test]
+	async fn ws_gm7_2025_run_expectancy() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let matrix = RunExpectancyMatrix::from_plays([&feed.live.plays]);
+		assert_ne!(matrix.occurrences[0][0], 0);
+
+		// over a complete half-inning, RE24 sums to the runs scored less the run expectancy of the leadoff state.
+		let plate_appearances = matrix.plate_appearances(&feed.live.plays);
+		let top_first = plate_appearances.iter().filter(|pa| *pa.play.about.inning == 1 && pa.play.about.inning_half == InningHalf::Top).collect::<Vec<_>>();
+		let runs = top_first.iter().map(|pa| pa.runs).sum::<usize>();
+		let re24 = top_first.iter().map(|pa| pa.re24).sum::<f64>();
+		#[allow(clippy::cast_precision_loss, reason = "small")]
+		let expected = runs as f64 - matrix.get(BaseOutState::default());
+		assert!((re24 - expected).abs() < 1e-9, "{re24} vs {expected}");
+
+		let players = matrix.players([&feed.live.plays]);
+		assert!((players.batters.values().sum::<f64>() + players.pitchers.values().sum::<f64>()).abs() < 1e-9);
+	}
+}
@@ -0,0 +1,238 @@
+//! Traditional scorer's notation (`6-3`, `F8`, `K`, `ꓘ`, `DP 6-4-3`, ...) and a scorecard grid built from it.
+//!
+//! Fielders are written by their position number; 1 for the pitcher through 9 for the right fielder.
+
+use std::collections::BTreeMap;
+
+use fxhash::FxHashMap;
+
+use crate::{HomeAway, game::{Base, BattingOrderIndex, Boxscore, Inning, Play, PlayEvent, Plays, RunnerData}, meta::{EventType, HitTrajectory}, person::PersonId};
+
+impl Play {
+	/// The batter's result in scorer's notation, `None` if the plate appearance isn't complete (or the play isn't a plate appearance, such as a caught stealing ending the inning).
+	///
+	/// | Result | Notation |
+	/// |--------|----------|
+	/// | Groundout, shortstop to first | `6-3` |
+	/// | Unassisted groundout | `3U` |
+	/// | Flyout, lineout, popup | `F8`, `L6`, `P4` |
+	/// | Strikeout swinging, looking | `K`, `ꓘ` |
+	/// | Double play | `DP 6-4-3` |
+	/// | Fielder's choice | `FC6` |
+	/// | Error | `E5` |
+	/// | Sacrifice bunt, fly | `SH 1-3`, `SF9` |
+	/// | Hits | `1B`, `2B`, `3B`, `HR` |
+	/// | Walks | `BB`, `IBB`, `HBP`, `CI` |
+	#[must_use]
+	pub fn notation(&self) -> Option<String> {
+		let event = self.result.completed_play_details.as_ref()?.event;
+		if !event.is_plate_appearance() {
+			return None;
+		}
+
+		Some(match event {
+			EventType::Single => "1B".to_owned(),
+			EventType::Double => "2B".to_owned(),
+			EventType::Triple => "3B".to_owned(),
+			EventType::HomeRun => "HR".to_owned(),
+			EventType::Walk => "BB".to_owned(),
+			EventType::IntentionalWalk => "IBB".to_owned(),
+			EventType::HitByPitch => "HBP".to_owned(),
+			EventType::CatchersInterference => "CI".to_owned(),
+			EventType::Strikeout => self.strikeout_notation().to_owned(),
+			EventType::StrikeoutDoublePlay => format!("{} DP {}", self.strikeout_notation(), self.fielders(true)),
+			EventType::StrikeoutTriplePlay => format!("{} TP {}", self.strikeout_notation(), self.fielders(true)),
+			EventType::GroundedIntoDoublePlay | EventType::DoublePlay | EventType::SacrificeFlyDoublePlay | EventType::SacrificeBuntDoublePlay => format!("DP {}", self.fielders(true)),
+			EventType::GroundedIntoTriplePlay | EventType::TriplePlay => format!("TP {}", self.fielders(true)),
+			EventType::FieldersChoice => format!("FC{}", self.first_fielder().unwrap_or_default()),
+			EventType::FieldersChoiceFieldOut => format!("FC {}", self.fielders(true)),
+			EventType::FieldError => format!("E{}", self.error_fielder().unwrap_or_default()),
+			EventType::SacrificeBunt => format!("SH {}", self.fielders(false)),
+			EventType::SacrificeFly => format!("SF{}", self.fielders(false)),
+			EventType::ForceOut => self.fielders(true),
+			EventType::FieldOut => self.field_out_notation(),
+			event => event.to_string(),
+		})
+	}
+
+	/// `ꓘ` if the final pitch was a called strike, otherwise `K`.
+	fn strikeout_notation(&self) -> &'static str {
+		let looking = self.play_events.iter().rev().find_map(|event| match event {
+			PlayEvent::Pitch { details, .. } => Some(details.call.as_str() == "C"),
+			_ => None,
+		});
+		if looking == Some(true) { "ꓘ" } else { "K" }
+	}
+
+	fn field_out_notation(&self) -> String {
+		let fielders = self.fielders(false);
+		if fielders.contains('-') {
+			return fielders;
+		}
+		let trajectory = self.play_events.iter().rev().find_map(|event| match event {
+			PlayEvent::Pitch { hit_data: Some(hit_data), .. } => hit_data.hit_trajectory,
+			_ => None,
+		});
+		match trajectory {
+			Some(HitTrajectory::FlyBall) => format!("F{fielders}"),
+			Some(HitTrajectory::LineDrive | HitTrajectory::BuntLineDrive) => format!("L{fielders}"),
+			Some(HitTrajectory::Popup | HitTrajectory::BuntPopup) => format!("P{fielders}"),
+			_ => format!("{fielders}U"),
+		}
+	}
+
+	/// Fielders that handled the ball on the way to the outs, `6-4-3`.
+	///
+	/// With `all_outs`, every out on the play is included in order, otherwise only the batter's.
+	fn fielders(&self, all_outs: bool) -> String {
+		let mut outs = self.runners.iter()
+			.filter(|runner| runner.movement.is_out && (all_outs || runner.details.runner.id == self.matchup.batter.id))
+			.collect::<Vec<_>>();
+		outs.sort_by_key(|runner| runner.movement.out_number);
+
+		let mut fielders: Vec<&str> = Vec::new();
+		for runner in outs {
+			for fielder in out_credits(runner) {
+				if fielders.last() != Some(&fielder) {
+					fielders.push(fielder);
+				}
+			}
+		}
+		fielders.join("-")
+	}
+
+	fn first_fielder(&self) -> Option<&str> {
+		self.runners.iter()
+			.flat_map(|runner| &runner.credits)
+			.next()
+			.map(|credit| credit.position.code.as_str())
+	}
+
+	fn error_fielder(&self) -> Option<&str> {
+		self.runners.iter()
+			.flat_map(|runner| &runner.credits)
+			.find(|credit| credit.credit.is_error())
+			.map(|credit| credit.position.code.as_str())
+	}
+}
+
+fn out_credits(runner: &RunnerData) -> impl Iterator<Item = &str> {
+	runner.credits.iter()
+		.filter(|credit| credit.credit.is_assist_or_putout())
+		.map(|credit| credit.position.code.as_str())
+}
+
+/// A single plate appearance in a [`Scorecard`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScorecardEntry {
+	/// Index of the [`Play`] within the game.
+	pub at_bat_idx: usize,
+	/// See [`Play::notation`].
+	pub notation: String,
+	pub rbi: usize,
+	/// Furthest base the batter-runner reached, on this play or later in the inning; [`Base::Home`] if they scored.
+	pub furthest_base: Option<Base>,
+	/// Which out of the inning the batter-runner was, if they were put out.
+	pub out_number: Option<usize>,
+}
+
+/// A full-game scorecard; each team's plate appearances keyed by spot in the batting order, then inning.
+///
+/// Batting around in an inning gives that spot multiple entries for the inning.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Scorecard {
+	pub teams: HomeAway<BTreeMap<BattingOrderIndex, BTreeMap<Inning, Vec<ScorecardEntry>>>>,
+}
+
+impl Scorecard {
+	/// Builds a scorecard from the `plays`, using the `boxscore` to find each batter's spot in the order.
+	///
+	/// ## Examples
+	/// ```no_run
+	/// let feed: LiveFeedResponse = ...;
+	///
+	/// let scorecard = Scorecard::new(&feed.live.plays, &feed.live.boxscore);
+	/// for (spot, innings) in &scorecard.teams.away {
+	///     for (inning, entries) in innings {
+	///         println!("{spot} {inning}: {}", entries.iter().map(|entry| entry.notation.as_str()).collect::<Vec<_>>().join(", "));
+	///     }
+	/// }
+	/// ```
+	#[must_use]
+	pub fn new(plays: &Plays, boxscore: &Boxscore) -> Self {
+		let mut scorecard = Self::default();
+		// latest plate appearance of each batter in the half-inning, for updating them as they move around the bases.
+		let mut latest = FxHashMap::<PersonId, (BattingOrderIndex, Inning, usize)>::default();
+		let mut half_inning = None;
+		for play in plays {
+			if half_inning.replace((play.about.inning, play.about.inning_half)) != Some((play.about.inning, play.about.inning_half)) {
+				latest.clear();
+			}
+			let side = play.about.inning_half.bats();
+			let team = scorecard.teams.as_mut().choose(side);
+			if let Some(notation) = play.notation()
+				&& let Some(spot) = boxscore.find_player_with_game_data(play.matchup.batter.id).and_then(|player| player.batting_order)
+			{
+				let entries = team.entry(spot).or_default().entry(play.about.inning).or_default();
+				latest.insert(play.matchup.batter.id, (spot, play.about.inning, entries.len()));
+				entries.push(ScorecardEntry {
+					at_bat_idx: play.about.at_bat_idx,
+					notation,
+					rbi: play.result.completed_play_details.as_ref().map_or(0, |details| details.rbi),
+					furthest_base: None,
+					out_number: None,
+				});
+			}
+			for runner in &play.runners {
+				let Some(&(spot, inning, idx)) = latest.get(&runner.details.runner.id) else { continue };
+				if let Some(entry) = team.get_mut(&spot).and_then(|innings| innings.get_mut(&inning)).and_then(|entries| entries.get_mut(idx)) {
+					entry.record_runner(runner);
+				}
+			}
+		}
+		scorecard
+	}
+}
+
+impl ScorecardEntry {
+	fn record_runner(&mut self, runner: &RunnerData) {
+		if runner.movement.is_out {
+			self.out_number = runner.movement.out_number;
+		} else {
+			self.furthest_base = self.furthest_base.max(runner.movement.end_base);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::collections::BTreeMap;
+
+	use crate::game::{Base, Inning, LiveFeedRequest, Scorecard};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_scorecard() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let plays = &feed.live.plays;
+		let notations = plays.iter().filter_map(|play| Some((play, play.notation()?))).collect::<Vec<_>>();
+		for (play, notation) in &notations {
+			assert!(!notation.is_empty() && notation != "U" && !notation.ends_with(' '), "incomplete notation {notation:?} at batter {}", play.matchup.batter.full_name);
+		}
+		let home_runs = notations.iter().filter(|(_, notation)| notation == "HR").map(|(play, _)| format!("{} {}", play.about.inning_half.three_char(), *play.about.inning)).collect::<Vec<_>>();
+		assert_eq!(home_runs, ["Bot 3", "Top 8", "Top 9", "Top 11"]);
+		let scorecard = Scorecard::new(plays, &feed.live.boxscore);
+		let eleventh = scorecard.teams.away.values().filter_map(|innings| innings.get(&Inning::from(11))).flatten().collect::<Vec<_>>();
+		assert!(eleventh.iter().any(|entry| entry.notation == "HR" && entry.furthest_base == Some(Base::Home) && entry.out_number.is_none()));
+		// batters retired at the plate never reach base, even when they're a later inning's automatic runner
+		for innings in [&scorecard.teams.away, &scorecard.teams.home] {
+			for entry in innings.values().flat_map(BTreeMap::values).flatten() {
+				if entry.notation.starts_with(['K', 'ꓘ']) || matches!(entry.notation.as_bytes(), [b'F' | b'L' | b'P', b'1'..=b'9']) {
+					assert_eq!(entry.furthest_base, None, "{} at bat {} reached base", entry.notation, entry.at_bat_idx);
+				}
+			}
+		}
+		let entries = scorecard.teams.map(|team| team.values().flat_map(|innings| innings.values()).map(Vec::len).sum::<usize>()).added();
+		assert_eq!(entries, plays.iter().filter(|play| play.notation().is_some()).count());
+	}
+}
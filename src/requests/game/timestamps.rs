@@ -28,7 +28,7 @@ impl FromStr for SimplifiedTimestamp {
 
 impl Display for SimplifiedTimestamp {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.0.format("%Y%d%m_%H%M%S"))
+        write!(f, "{}", self.0.format("%Y%m%d_%H%M%S"))
     }
 }
 
@@ -0,0 +1,219 @@
+//! Pitch flight physics from the constant-acceleration (PITCHf/x-style) model in [`PitchData`].
+//!
+//! The model is `p(t) = p0 + v0 t + ½ a t²` along each axis, where `t = 0` is when the pitch is at `y0` (typically 50 feet), not release.
+//! Coordinates are from the catcher's perspective: `x` is horizontal, `y` is towards the mound from the back tip of home plate, `z` is height; all in feet.
+
+use crate::game::PitchData;
+
+/// Distance from the back tip of home plate to the front of the pitching rubber, in feet.
+pub const RUBBER_DISTANCE: f64 = 60.5;
+
+/// Distance from the back tip of home plate to the front of the plate, where [`PitchData::pX`] and [`PitchData::pZ`] are measured, in feet.
+pub const FRONT_OF_PLATE: f64 = 17.0 / 12.0;
+
+/// Acceleration due to gravity, in feet/s^2.
+pub const GRAVITY: f64 = 32.174;
+
+/// League average release extension, in feet; the baseline for [`PitchData::perceived_velocity`].
+pub const AVERAGE_EXTENSION: f64 = 6.3;
+
+const FEET_PER_SECOND_PER_MPH: f64 = 5280.0 / 3600.0;
+
+/// A position (in feet) or velocity (in feet per second) along the three axes of [`PitchData`].
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub struct PitchVector {
+	pub x: f64,
+	pub y: f64,
+	pub z: f64,
+}
+
+impl PitchVector {
+	/// Length of the vector; the speed, for a velocity.
+	#[must_use]
+	pub fn magnitude(&self) -> f64 {
+		self.z.mul_add(self.z, self.x.mul_add(self.x, self.y * self.y)).sqrt()
+	}
+}
+
+impl PitchData {
+	/// Position of the pitch `t` seconds after it was at [`y0`](Self::y0).
+	#[must_use]
+	pub fn position_at(&self, t: f64) -> PitchVector {
+		PitchVector {
+			x: (0.5 * self.aX * t).mul_add(t, self.vX0.mul_add(t, self.x0)),
+			y: (0.5 * self.aY * t).mul_add(t, self.vY0.mul_add(t, self.y0)),
+			z: (0.5 * self.aZ * t).mul_add(t, self.vZ0.mul_add(t, self.z0)),
+		}
+	}
+
+	/// Velocity of the pitch `t` seconds after it was at [`y0`](Self::y0), in feet per second.
+	#[must_use]
+	pub const fn velocity_at(&self, t: f64) -> PitchVector {
+		PitchVector {
+			x: self.aX.mul_add(t, self.vX0),
+			y: self.aY.mul_add(t, self.vY0),
+			z: self.aZ.mul_add(t, self.vZ0),
+		}
+	}
+
+	/// Time at which the pitch is `y` feet from the back tip of home plate, relative to when it was at [`y0`](Self::y0).
+	///
+	/// Negative for points before `y0`, such as the release point. `NaN` if the pitch never reaches `y`.
+	#[must_use]
+	pub fn time_at_y(&self, y: f64) -> f64 {
+		if self.aY == 0.0 {
+			return (y - self.y0) / self.vY0;
+		}
+		// earlier root of ½ aY t² + vY0 t + (y0 - y) = 0, the pitch is moving towards the plate (vY0 < 0).
+		let discriminant = self.vY0.mul_add(self.vY0, -2.0 * self.aY * (self.y0 - y));
+		(-self.vY0 - discriminant.sqrt()) / self.aY
+	}
+
+	/// Position of the pitch when it is `y` feet from the back tip of home plate.
+	#[must_use]
+	pub fn position_at_y(&self, y: f64) -> PitchVector {
+		self.position_at(self.time_at_y(y))
+	}
+
+	/// Velocity of the pitch when it is `y` feet from the back tip of home plate, in feet per second.
+	#[must_use]
+	pub fn velocity_at_y(&self, y: f64) -> PitchVector {
+		self.velocity_at(self.time_at_y(y))
+	}
+
+	/// Distance from the back tip of home plate to the release point, measured in feet.
+	///
+	/// Falls back on [`AVERAGE_EXTENSION`] when the extension wasn't tracked.
+	#[must_use]
+	pub const fn release_y(&self) -> f64 {
+		RUBBER_DISTANCE - if self.extension.is_nan() { AVERAGE_EXTENSION } else { self.extension }
+	}
+
+	/// Position of the pitch at release.
+	#[must_use]
+	pub fn release_position(&self) -> PitchVector {
+		self.position_at_y(self.release_y())
+	}
+
+	/// Position of the pitch as it crosses the front of home plate, a recomputed ([`pX`](Self::pX), [`FRONT_OF_PLATE`], [`pZ`](Self::pZ)).
+	#[must_use]
+	pub fn plate_position(&self) -> PitchVector {
+		self.position_at_y(FRONT_OF_PLATE)
+	}
+
+	/// Velocity of the pitch as it crosses the front of home plate, in feet per second.
+	#[must_use]
+	pub fn plate_velocity(&self) -> PitchVector {
+		self.velocity_at_y(FRONT_OF_PLATE)
+	}
+
+	/// Speed of the pitch as it crosses the front of home plate, a recomputed [`plate_speed`](Self::plate_speed).
+	///
+	/// Measured in mph.
+	#[must_use]
+	pub fn plate_speed_mph(&self) -> f64 {
+		self.plate_velocity().magnitude() / FEET_PER_SECOND_PER_MPH
+	}
+
+	/// Time from release to crossing the front of home plate, a recomputed [`time_to_plate`](Self::time_to_plate).
+	///
+	/// Measured in seconds.
+	#[must_use]
+	pub fn flight_time(&self) -> f64 {
+		self.time_at_y(FRONT_OF_PLATE) - self.time_at_y(self.release_y())
+	}
+
+	/// Horizontal movement from release to the plate compared to a pitch thrown with the same velocity and no spin, catchers perspective.
+	///
+	/// Unlike [`pfxX`](Self::pfxX), this is measured over the whole flight rather than from y=40 feet.
+	///
+	/// Measured in inches.
+	#[must_use]
+	pub fn horizontal_break(&self) -> f64 {
+		let t = self.flight_time();
+		// ½ a t², in inches
+		6.0 * self.aX * t * t
+	}
+
+	/// Vertical movement from release to the plate compared to a pitch thrown with the same velocity and no spin, positive means rise.
+	///
+	/// This is the induced vertical break, [`induced_vertical_movement`](Self::induced_vertical_movement).
+	///
+	/// Measured in inches.
+	#[must_use]
+	pub fn induced_vertical_break(&self) -> f64 {
+		let t = self.flight_time();
+		6.0 * (self.aZ + GRAVITY) * t * t
+	}
+
+	/// Vertical movement from release to the plate compared to a pitch thrown with the same velocity in a vacuum, gravity included.
+	///
+	/// Measured in inches.
+	#[must_use]
+	pub fn total_vertical_break(&self) -> f64 {
+		let t = self.flight_time();
+		6.0 * self.aZ * t * t
+	}
+
+	/// Vertical approach angle (VAA) at the front of home plate, negative means the pitch is descending.
+	///
+	/// Measured in degrees.
+	#[must_use]
+	pub fn vertical_approach_angle(&self) -> f64 {
+		let velocity = self.plate_velocity();
+		-(velocity.z / velocity.y).atan().to_degrees()
+	}
+
+	/// Horizontal approach angle (HAA) at the front of home plate, catchers perspective, positive means the pitch is moving towards positive `x`.
+	///
+	/// Measured in degrees.
+	#[must_use]
+	pub fn horizontal_approach_angle(&self) -> f64 {
+		let velocity = self.plate_velocity();
+		-(velocity.x / velocity.y).atan().to_degrees()
+	}
+
+	/// The release speed a pitcher with [`AVERAGE_EXTENSION`] would need for the pitch to reach the plate in the same time.
+	///
+	/// More extension means less distance to the plate, so the pitch plays faster than its [`release_speed`](Self::release_speed).
+	///
+	/// Measured in mph.
+	#[must_use]
+	pub fn perceived_velocity(&self) -> f64 {
+		self.release_speed * (RUBBER_DISTANCE - AVERAGE_EXTENSION - FRONT_OF_PLATE) / (self.release_y() - FRONT_OF_PLATE)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{LiveFeedRequest, PlayEvent};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_trajectories() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let pitches = feed.live.plays.iter()
+			.flat_map(|play| &play.play_events)
+			.filter_map(|event| match event {
+				PlayEvent::Pitch { pitch_data: Some(pitch_data), .. } => Some(pitch_data),
+				_ => None,
+			})
+			.filter(|pitch_data| !pitch_data.pX.is_nan() && !pitch_data.vY0.is_nan())
+			.collect::<Vec<_>>();
+		assert_ne!(pitches.len(), 0);
+
+		for pitch in pitches {
+			let plate = pitch.plate_position();
+			assert!((plate.x - pitch.pX).abs() < 0.05, "pX: {} vs {}", plate.x, pitch.pX);
+			assert!((plate.z - pitch.pZ).abs() < 0.05, "pZ: {} vs {}", plate.z, pitch.pZ);
+			if !pitch.plate_speed.is_nan() {
+				assert!((pitch.plate_speed_mph() - pitch.plate_speed).abs() < 0.5, "plate speed: {} vs {}", pitch.plate_speed_mph(), pitch.plate_speed);
+			}
+			if !pitch.induced_vertical_movement.is_nan() {
+				assert!((pitch.induced_vertical_break() - pitch.induced_vertical_movement).abs() < 2.0, "IVB: {} vs {}", pitch.induced_vertical_break(), pitch.induced_vertical_movement);
+			}
+			assert!(pitch.vertical_approach_angle() < 0.0);
+			assert!(pitch.flight_time() > 0.3 && pitch.flight_time() < 0.6);
+		}
+	}
+}
@@ -0,0 +1,326 @@
+//! Strike zone classification of called pitches and per-game home plate umpire scorecards.
+//!
+//! Only called balls and strikes are graded; swings, pitches in play, and automatic balls and strikes are not an umpire's judgement.
+
+use crate::{HomeAway, TeamSide, game::{Boxscore, GameState, OfficialType, PitchData, Play, PlayEvent, Plays, WinExpectancy}, meta::EventType, person::PersonId};
+
+/// Half the width of home plate, in feet.
+pub const PLATE_HALF_WIDTH: f64 = 17.0 / 24.0;
+
+/// Radius of a baseball, in feet.
+pub const BALL_RADIUS: f64 = 2.9 / 24.0;
+
+/// Approximate league-wide run value of each count for the batting team, relative to the start of a plate appearance; indexed `[balls][strikes]`.
+const COUNT_RUN_VALUES: [[f64; 3]; 4] = [
+	[0.000, -0.044, -0.103],
+	[0.037, -0.015, -0.081],
+	[0.090, 0.040, -0.040],
+	[0.183, 0.150, 0.085],
+];
+
+/// Run value of a walk, see [`COUNT_RUN_VALUES`].
+const WALK_RUN_VALUE: f64 = 0.320;
+
+/// Run value of a strikeout, see [`COUNT_RUN_VALUES`].
+const STRIKEOUT_RUN_VALUE: f64 = -0.270;
+
+/// A ball or strike, either as called or as it should have been.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ZoneCall {
+	Ball,
+	Strike,
+}
+
+/// Vertical bounds of the strike zone in a [`StrikeZoneModel`].
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum ZoneHeight {
+	/// The batter's own zone, [`PitchData::sz_bot`] to [`PitchData::sz_top`].
+	#[default]
+	Batter,
+	/// The same zone for every batter, measured in feet above home plate.
+	Fixed { bottom: f64, top: f64 },
+}
+
+/// How to decide whether a pitch was a true ball or strike.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct StrikeZoneModel {
+	/// Half the width of the zone, in feet.
+	pub half_width: f64,
+	/// Any part of the ball within this distance of the zone is a strike, in feet.
+	pub ball_radius: f64,
+	pub height: ZoneHeight,
+	/// Pitches within this distance of the edge of the zone (either way) are too close to call, and never count as missed; in feet.
+	pub buffer: f64,
+}
+
+impl StrikeZoneModel {
+	/// The rulebook zone; the width of home plate and the batter's own top and bottom, with no buffer.
+	pub const RULEBOOK: Self = Self {
+		half_width: PLATE_HALF_WIDTH,
+		ball_radius: BALL_RADIUS,
+		height: ZoneHeight::Batter,
+		buffer: 0.0,
+	};
+
+	/// Signed distance from the edge of the ball to the edge of the zone at the front of home plate, in feet.
+	///
+	/// Negative means the ball overlaps the zone. `NaN` if the pitch wasn't tracked.
+	#[must_use]
+	pub fn distance(&self, pitch: &PitchData) -> f64 {
+		let (bottom, top) = match self.height {
+			ZoneHeight::Batter => (pitch.sz_bot, pitch.sz_top),
+			ZoneHeight::Fixed { bottom, top } => (bottom, top),
+		};
+		let dx = pitch.pX.abs() - self.half_width;
+		let dz = (bottom - pitch.pZ).max(pitch.pZ - top);
+		let center_distance = if dx <= 0.0 && dz <= 0.0 { dx.max(dz) } else { dx.max(0.0).hypot(dz.max(0.0)) };
+		center_distance - self.ball_radius
+	}
+
+	/// Whether the pitch should have been a ball or strike, `None` if it wasn't tracked.
+	#[must_use]
+	pub fn classify(&self, pitch: &PitchData) -> Option<ZoneCall> {
+		let distance = self.distance(pitch);
+		if distance.is_nan() {
+			None
+		} else if distance <= 0.0 {
+			Some(ZoneCall::Strike)
+		} else {
+			Some(ZoneCall::Ball)
+		}
+	}
+}
+
+impl Default for StrikeZoneModel {
+	fn default() -> Self {
+		Self::RULEBOOK
+	}
+}
+
+/// A pitch the batter took, graded against a [`StrikeZoneModel`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct CalledPitch {
+	pub at_bat_idx: usize,
+	/// Index of the pitch within [`Play::play_events`](crate::game::Play::play_events).
+	pub play_event_idx: usize,
+	/// Home plate umpire at the time, `None` if unknown (such as the umpire replaced mid-game when the feed doesn't say who).
+	pub umpire: Option<PersonId>,
+	pub batting: TeamSide,
+	/// Balls and strikes before the pitch.
+	pub balls: u8,
+	pub strikes: u8,
+	pub call: ZoneCall,
+	pub true_call: ZoneCall,
+	/// See [`StrikeZoneModel::distance`].
+	pub distance: f64,
+	/// Whether the call was wrong, beyond the model's [`buffer`](StrikeZoneModel::buffer).
+	pub is_missed: bool,
+	/// Runs the call gained the batting team compared to the correct call; `0.0` unless missed.
+	pub run_impact: f64,
+}
+
+impl CalledPitch {
+	/// The team that benefited from this call, `None` if it was correct.
+	#[must_use]
+	pub fn favored(&self) -> Option<TeamSide> {
+		if !self.is_missed {
+			None
+		} else if self.run_impact > 0.0 {
+			Some(self.batting)
+		} else {
+			Some(!self.batting)
+		}
+	}
+}
+
+/// A home plate umpire's ball and strike calls for a single game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UmpireScorecard {
+	/// `None` for calls made by an unknown umpire, see [`CalledPitch::umpire`].
+	pub umpire: Option<PersonId>,
+	pub calls: Vec<CalledPitch>,
+}
+
+impl UmpireScorecard {
+	/// Grades every called pitch in the game, one scorecard per home plate umpire; more than one if the umpire was replaced mid-game.
+	///
+	/// ## Examples
+	/// ```no_run
+	/// let feed: LiveFeedResponse = ...;
+	///
+	/// for scorecard in UmpireScorecard::for_game(&feed.live.plays, &feed.live.boxscore, &StrikeZoneModel::default()) {
+	///     println!("{:?}: {:.1}% accurate, {:.1}% consistent", scorecard.umpire, scorecard.accuracy() * 100.0, scorecard.consistency() * 100.0);
+	/// }
+	/// ```
+	#[must_use]
+	pub fn for_game(plays: &Plays, boxscore: &Boxscore, model: &StrikeZoneModel) -> Vec<Self> {
+		let final_umpire = boxscore.officials.iter().find(|official| official.official_type == OfficialType::HomePlate).map(|official| official.official.id);
+		let substitution = plays.iter()
+			.flat_map(|play| &play.play_events)
+			.find(|event| matches!(event, PlayEvent::Action { details, .. } if details.event == EventType::UmpireSubstitution) && event.umpire.is_some() && event.umpire == final_umpire);
+		let mut umpire = substitution.map_or(final_umpire, |substitution| substitution.replaced_player);
+
+		let mut scorecards: Vec<Self> = Vec::new();
+		for play in plays {
+			for (play_event_idx, event) in play.play_events.iter().enumerate() {
+				if substitution.is_some_and(|substitution| std::ptr::eq(substitution, event)) {
+					umpire = final_umpire;
+				}
+				let Some(called) = grade(play, play_event_idx, umpire, model) else { continue };
+				match scorecards.iter_mut().find(|scorecard| scorecard.umpire == umpire) {
+					Some(scorecard) => scorecard.calls.push(called),
+					None => scorecards.push(Self { umpire, calls: vec![called] }),
+				}
+			}
+		}
+		scorecards
+	}
+
+	/// Calls that were wrong, see [`CalledPitch::is_missed`].
+	pub fn misses(&self) -> impl Iterator<Item = &CalledPitch> {
+		self.calls.iter().filter(|call| call.is_missed)
+	}
+
+	/// Fraction of calls that were correct.
+	#[must_use]
+	#[allow(clippy::cast_precision_loss, reason = "a game has far fewer than 2^52 pitches")]
+	pub fn accuracy(&self) -> f64 {
+		if self.calls.is_empty() {
+			return 1.0;
+		}
+		1.0 - self.misses().count() as f64 / self.calls.len() as f64
+	}
+
+	/// Fraction of calls consistent with the umpire's own zone, regardless of whether that zone was correct.
+	///
+	/// The umpire's zone is the single expansion (or shrinking) of the [`StrikeZoneModel`] which agrees with the most calls;
+	/// an umpire with a large but consistent zone scores well here and poorly in [`accuracy`](Self::accuracy).
+	#[must_use]
+	#[allow(clippy::cast_precision_loss, reason = "a game has far fewer than 2^52 pitches")]
+	pub fn consistency(&self) -> f64 {
+		if self.calls.is_empty() {
+			return 1.0;
+		}
+		let mut calls = self.calls.iter().map(|call| (call.distance, call.call)).collect::<Vec<_>>();
+		calls.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+		// sweep the zone edge outwards; everything inside it should be a strike, everything outside it a ball.
+		let mut agreeing = calls.iter().filter(|(_, call)| *call == ZoneCall::Ball).count();
+		let mut best = agreeing;
+		for (_, call) in &calls {
+			match call {
+				ZoneCall::Strike => agreeing += 1,
+				ZoneCall::Ball => agreeing -= 1,
+			}
+			best = best.max(agreeing);
+		}
+		best as f64 / calls.len() as f64
+	}
+
+	/// Runs each team gained from missed calls, whether batting or pitching.
+	#[must_use]
+	pub fn favor(&self) -> HomeAway<f64> {
+		let mut favor = HomeAway::new(0.0, 0.0);
+		for call in self.misses() {
+			if let Some(side) = call.favored() {
+				*favor.as_mut().choose(side) += call.run_impact.abs();
+			}
+		}
+		favor
+	}
+
+	/// The `count` missed calls with the largest run impact at their count, largest first; not weighted by the game situation, see [`Self::highest_leverage_misses`].
+	#[must_use]
+	pub fn largest_run_impact_misses(&self, count: usize) -> Vec<&CalledPitch> {
+		let mut misses = self.misses().collect::<Vec<_>>();
+		misses.sort_by(|a, b| b.run_impact.abs().total_cmp(&a.run_impact.abs()));
+		misses.truncate(count);
+		misses
+	}
+
+	/// The `count` missed calls with the largest run impact weighted by the [`leverage index`](WinExpectancy::leverage_index) of the inning, score, outs and runners at the time; largest first.
+	///
+	/// Each miss is paired with its leverage index. `plays` must be the plays this scorecard was built from.
+	#[must_use]
+	pub fn highest_leverage_misses<'a>(&'a self, plays: &Plays, model: &WinExpectancy, count: usize) -> Vec<(&'a CalledPitch, f64)> {
+		let mut misses = self.misses()
+			.map(|miss| (miss, GameState::before_event(plays, miss.at_bat_idx, miss.play_event_idx).map_or(1.0, |state| model.leverage_index(state))))
+			.collect::<Vec<_>>();
+		misses.sort_by(|(a, a_leverage), (b, b_leverage)| (b.run_impact.abs() * b_leverage).total_cmp(&(a.run_impact.abs() * a_leverage)));
+		misses.truncate(count);
+		misses
+	}
+}
+
+/// Grades the `play_event_idx`th event of `play` if it's a called pitch.
+fn grade(play: &Play, play_event_idx: usize, umpire: Option<PersonId>, model: &StrikeZoneModel) -> Option<CalledPitch> {
+	let event = &play.play_events[play_event_idx];
+	let PlayEvent::Pitch { details, pitch_data: Some(pitch_data), .. } = event else { return None };
+	let call = match details.call.as_str() {
+		"B" | "*B" => ZoneCall::Ball,
+		"C" => ZoneCall::Strike,
+		_ => return None,
+	};
+	let true_call = model.classify(pitch_data)?;
+	let distance = model.distance(pitch_data);
+	let (balls, strikes) = match call {
+		ZoneCall::Ball => (event.count.balls.saturating_sub(1).min(3), event.count.strikes.min(2)),
+		ZoneCall::Strike => (event.count.balls.min(3), event.count.strikes.saturating_sub(1).min(2)),
+	};
+	let is_missed = call != true_call && distance.abs() > model.buffer;
+	Some(CalledPitch {
+		at_bat_idx: play.about.at_bat_idx,
+		play_event_idx,
+		umpire,
+		batting: play.about.inning_half.bats(),
+		balls,
+		strikes,
+		call,
+		true_call,
+		distance,
+		is_missed,
+		run_impact: if is_missed { run_value(balls, strikes, call) - run_value(balls, strikes, true_call) } else { 0.0 },
+	})
+}
+
+/// Run value for the batting team after `call` in a `balls`-`strikes` count.
+fn run_value(balls: u8, strikes: u8, call: ZoneCall) -> f64 {
+	match call {
+		ZoneCall::Ball if balls >= 3 => WALK_RUN_VALUE,
+		ZoneCall::Ball => COUNT_RUN_VALUES[usize::from(balls) + 1][usize::from(strikes)],
+		ZoneCall::Strike if strikes >= 2 => STRIKEOUT_RUN_VALUE,
+		ZoneCall::Strike => COUNT_RUN_VALUES[usize::from(balls)][usize::from(strikes) + 1],
+	}
+}
+
+/// Runs the batting team gains from a ball rather than a strike in a `balls`-`strikes` count; how much a call is worth.
+pub(super) fn call_run_swing(balls: u8, strikes: u8) -> f64 {
+	let (balls, strikes) = (balls.min(3), strikes.min(2));
+	run_value(balls, strikes, ZoneCall::Ball) - run_value(balls, strikes, ZoneCall::Strike)
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{LiveFeedRequest, OfficialType, StrikeZoneModel, UmpireScorecard, WinExpectancy};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_umpire_scorecard() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let scorecards = UmpireScorecard::for_game(&feed.live.plays, &feed.live.boxscore, &StrikeZoneModel::default());
+		let home_plate = feed.live.boxscore.officials.iter().find(|official| official.official_type == OfficialType::HomePlate).map(|official| official.official.id);
+		assert_eq!(scorecards.iter().map(|scorecard| scorecard.umpire).collect::<Vec<_>>(), [home_plate]);
+		for scorecard in &scorecards {
+			// an extra-inning game has well over a hundred taken pitches
+			assert!(scorecard.calls.len() > 100, "only {} calls", scorecard.calls.len());
+			let misses = scorecard.largest_run_impact_misses(3);
+			assert!(misses.iter().all(|miss| miss.is_missed && miss.call != miss.true_call));
+			assert!(misses.windows(2).all(|pair| pair[0].run_impact.abs() >= pair[1].run_impact.abs()));
+			let leveraged = scorecard.highest_leverage_misses(&feed.live.plays, &WinExpectancy::default(), 3);
+			assert_eq!(leveraged.len(), misses.len());
+			assert!(leveraged.iter().all(|(miss, leverage)| miss.is_missed && *leverage > 0.0));
+			assert!(leveraged.windows(2).all(|pair| pair[0].0.run_impact.abs() * pair[0].1 >= pair[1].0.run_impact.abs() * pair[1].1));
+			assert!((0.0..=1.0).contains(&scorecard.accuracy()));
+			assert!(scorecard.consistency() >= scorecard.accuracy());
+		}
+	}
+}
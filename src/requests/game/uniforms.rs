@@ -1 +1,136 @@
+//! The uniforms each team wore (or will wear) in a game; jersey, pants, cap, etc.
+//!
+//! For every uniform a team has, see [`team::uniforms`](crate::team::uniforms).
 
+use std::fmt::{Display, Formatter};
+#[cfg(feature = "cache")]
+use std::sync::Arc;
+
+use bon::Builder;
+use itertools::Itertools;
+use serde::Deserialize;
+
+use crate::{Copyright, HomeAway, cache::RequestableEntrypoint, game::GameId, request::RequestURL, team::uniforms::{TeamUniformAssets, UniformAsset}};
+
+/// The uniforms of each requested game.
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameUniformsResponse {
+	pub copyright: Copyright,
+	#[serde(rename = "uniforms")]
+	pub games: Vec<GameUniforms>,
+}
+
+/// The uniforms worn by each team in a game.
+#[derive(Debug, Deserialize, PartialEq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct GameUniforms {
+	#[serde(rename = "gamePk")]
+	pub game_id: GameId,
+	#[serde(flatten)]
+	pub teams: HomeAway<TeamUniformAssets>,
+}
+
+impl GameUniformsResponse {
+	/// The uniforms of `game`, if it was requested.
+	#[must_use]
+	pub fn game(&self, game: GameId) -> Option<&GameUniforms> {
+		self.games.iter().find(|uniforms| uniforms.game_id == game)
+	}
+
+	/// Adds every asset to the [`UniformAsset`] cache, so later lookups through [`RequestableEntrypoint::as_complete_or_request`] (and [`TeamUniformAssets::resolve`]) don't refetch each team's uniforms.
+	#[cfg(feature = "cache")]
+	pub async fn cache_assets(&self) {
+		let assets = self.games.iter().flat_map(|game| [&game.teams.home, &game.teams.away]).flat_map(|team| team.uniform_assets.iter().cloned());
+		<UniformAsset as crate::cache::Requestable>::get_cache_table().write().await.add_entries(assets);
+	}
+}
+
+impl TeamUniformAssets {
+	/// The asset of the category with the given name (ex: `"Jersey"`), ignoring case.
+	#[must_use]
+	pub fn asset(&self, category: &str) -> Option<&UniformAsset> {
+		self.uniform_assets.iter().find(|asset| asset.category.name.eq_ignore_ascii_case(category))
+	}
+
+	#[must_use]
+	pub fn jersey(&self) -> Option<&UniformAsset> {
+		self.asset("Jersey")
+	}
+
+	#[must_use]
+	pub fn pants(&self) -> Option<&UniformAsset> {
+		self.asset("Pants")
+	}
+
+	#[must_use]
+	pub fn cap(&self) -> Option<&UniformAsset> {
+		self.asset("Cap")
+	}
+
+	/// Each asset resolved against the [`UniformAsset`] cache, requesting the team's uniforms for any that aren't cached yet.
+	///
+	/// Call [`GameUniformsResponse::cache_assets`] first to skip those requests.
+	///
+	/// # Errors
+	/// See [`RequestableEntrypoint::as_complete_or_request`].
+	#[cfg(feature = "cache")]
+	pub async fn resolve(&self) -> Result<Vec<Arc<UniformAsset>>, crate::cache::Error<UniformAsset>> {
+		let mut assets = Vec::with_capacity(self.uniform_assets.len());
+		for asset in &self.uniform_assets {
+			assets.push(asset.as_complete_or_request().await?);
+		}
+		Ok(assets)
+	}
+}
+
+/// Returns a [`GameUniformsResponse`]
+#[derive(Builder)]
+#[builder(derive(Into))]
+pub struct GameUniformsRequest {
+	#[builder(setters(vis = "", name = games_internal))]
+	games: Vec<GameId>,
+}
+
+impl<S: game_uniforms_request_builder::State> GameUniformsRequestBuilder<S> {
+	pub fn games(self, games: Vec<impl Into<GameId>>) -> GameUniformsRequestBuilder<game_uniforms_request_builder::SetGames<S>>
+	where
+		S::Games: game_uniforms_request_builder::IsUnset,
+	{
+		self.games_internal(games.into_iter().map(Into::into).collect())
+	}
+}
+
+impl<S: game_uniforms_request_builder::State + game_uniforms_request_builder::IsComplete> crate::request::RequestURLBuilderExt for GameUniformsRequestBuilder<S> {
+	type Built = GameUniformsRequest;
+}
+
+impl Display for GameUniformsRequest {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "http://statsapi.mlb.com/api/v1/uniforms/game{}", gen_params! { "gamePks": self.games.iter().copied().join(",") })
+	}
+}
+
+impl RequestURL for GameUniformsRequest {
+	type Response = GameUniformsResponse;
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::GameUniformsRequest;
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_uniforms() {
+		let response = GameUniformsRequest::builder().games(vec![813_024]).build_and_get().await.unwrap();
+		let uniforms = response.game(813_024.into()).expect("Expected the requested game");
+		assert_ne!(uniforms.teams.home.team_id, uniforms.teams.away.team_id);
+		#[cfg(feature = "cache")]
+		{
+			response.cache_assets().await;
+			let jersey = uniforms.teams.home.jersey().expect("Expected a home jersey");
+			let resolved = uniforms.teams.home.resolve().await.unwrap();
+			assert!(resolved.iter().any(|asset| asset.code == jersey.code && asset.category.name == jersey.category.name));
+		}
+	}
+}
@@ -0,0 +1,487 @@
+//! Win expectancy, win probability added (WPA), and leverage index (LI), computed locally.
+//!
+//! Works for any game with plays, including minor league and historical games that the win probability endpoint doesn't cover.
+
+use fxhash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+use crate::{HomeAway, game::{BaseOccupancy, BaseOutState, Inning, InningHalf, Play, Plays, RunExpectancyMatrix}, person::PersonId};
+
+/// Mean runs scored in a half-inning, from the leadoff state of [`RunExpectancyMatrix::TYPICAL`].
+const RUNS_PER_HALF_INNING: f64 = 0.481;
+
+/// Variance of the runs scored in a half-inning.
+const RUN_VARIANCE_PER_HALF_INNING: f64 = 1.0;
+
+/// Weight (in games) given to the analytic estimate when blending it with observed outcomes.
+const PRIOR_GAMES: f64 = 5.0;
+
+/// League frequencies of plate appearance outcomes used for [`WinExpectancy::leverage_index`].
+const OUTCOMES: [(PlateAppearanceOutcome, f64); 6] = [
+	(PlateAppearanceOutcome::Out, 0.68),
+	(PlateAppearanceOutcome::Walk, 0.09),
+	(PlateAppearanceOutcome::Single, 0.15),
+	(PlateAppearanceOutcome::Double, 0.045),
+	(PlateAppearanceOutcome::Triple, 0.005),
+	(PlateAppearanceOutcome::HomeRun, 0.03),
+];
+
+/// Everything win expectancy is keyed by.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct GameState {
+	pub inning: Inning,
+	pub inning_half: InningHalf,
+	pub outs: u8,
+	pub bases: BaseOccupancy,
+	/// Home team's runs minus the away team's.
+	pub run_differential: i32,
+}
+
+impl GameState {
+	/// Start of the game.
+	#[must_use]
+	pub fn starting() -> Self {
+		Self { inning: Inning::starting(), inning_half: InningHalf::starting(), outs: 0, bases: BaseOccupancy::default(), run_differential: 0 }
+	}
+
+	/// Start of the next half-inning.
+	#[must_use]
+	pub fn next_half_inning(&self) -> Self {
+		Self {
+			inning: if self.inning_half == InningHalf::Bottom { Inning::from(*self.inning + 1) } else { self.inning },
+			inning_half: match self.inning_half {
+				InningHalf::Top => InningHalf::Bottom,
+				InningHalf::Bottom => InningHalf::Top,
+			},
+			outs: 0,
+			bases: BaseOccupancy::default(),
+			run_differential: self.run_differential,
+		}
+	}
+
+	/// The state just before the `play_event_idx`th event of the `at_bat_idx`th play, `None` if there's no such play.
+	///
+	/// Runs scored earlier in the same play (such as on a wild pitch) aren't counted until the play ends.
+	#[must_use]
+	pub fn before_event(plays: &Plays, at_bat_idx: usize, play_event_idx: usize) -> Option<Self> {
+		let play = plays.get(at_bat_idx).filter(|play| play.about.at_bat_idx == at_bat_idx)?;
+		let previous = at_bat_idx.checked_sub(1).and_then(|previous| plays.get(previous));
+		let (bases, outs) = play.starting_situation(previous);
+		let outs = play_event_idx.checked_sub(1).and_then(|previous| play.play_events.get(previous)).map_or(outs, |previous| previous.count.outs);
+		Some(Self {
+			inning: play.about.inning,
+			inning_half: play.about.inning_half,
+			outs: outs.min(2),
+			bases: play.bases_before(play_event_idx, bases),
+			run_differential: previous.map_or(0, run_differential),
+		})
+	}
+
+	/// The batting team's runs minus the fielding team's.
+	#[must_use]
+	pub const fn batting_run_differential(&self) -> i32 {
+		match self.inning_half {
+			InningHalf::Top => -self.run_differential,
+			InningHalf::Bottom => self.run_differential,
+		}
+	}
+}
+
+/// Home wins from a [`GameState`], one row of a win expectancy table.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct WinExpectancyEntry {
+	pub inning: usize,
+	pub is_bottom: bool,
+	pub outs: u8,
+	/// See [`BaseOccupancy::index`].
+	pub bases: usize,
+	/// Home team's runs minus the away team's.
+	pub run_differential: i32,
+	pub home_wins: usize,
+	pub games: usize,
+}
+
+/// Win probability of the home team from any [`GameState`].
+///
+/// Observed outcomes (from [`Self::from_plays`] or a loaded table) are blended with an analytic estimate built on run expectancy, so sparse or unseen states still get a sensible probability.
+/// With no observations it's purely analytic, see [`Self::default`].
+///
+/// ## Examples
+/// ```no_run
+/// let feed: LiveFeedResponse = ...;
+///
+/// let model = WinExpectancy::default();
+/// for play in model.top_plays(&feed.live.plays, 5) {
+///     println!("{:+.1}%: {}", play.home_wpa() * 100.0, play.play.result.completed_play_details.as_ref().unwrap().description);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(from = "__WinExpectancyStruct", into = "__WinExpectancyStruct")]
+pub struct WinExpectancy {
+	/// Keyed by [`Self::bucket`].
+	records: FxHashMap<GameState, (usize, usize)>,
+	pub scheduled_innings: usize,
+	pub run_expectancy: RunExpectancyMatrix,
+	/// Average probability swing of a plate appearance, a leverage index of 1.
+	average_swing: f64,
+}
+
+#[doc(hidden)]
+#[derive(Serialize, Deserialize)]
+struct __WinExpectancyStruct {
+	scheduled_innings: usize,
+	run_expectancy: RunExpectancyMatrix,
+	entries: Vec<WinExpectancyEntry>,
+}
+
+impl From<__WinExpectancyStruct> for WinExpectancy {
+	fn from(__WinExpectancyStruct { scheduled_innings, run_expectancy, entries }: __WinExpectancyStruct) -> Self {
+		Self::from_entries(entries, scheduled_innings, run_expectancy)
+	}
+}
+
+impl From<WinExpectancy> for __WinExpectancyStruct {
+	fn from(value: WinExpectancy) -> Self {
+		Self { scheduled_innings: value.scheduled_innings, entries: value.entries(), run_expectancy: value.run_expectancy }
+	}
+}
+
+impl Default for WinExpectancy {
+	/// Purely analytic for nine inning games, using [`RunExpectancyMatrix::TYPICAL`].
+	fn default() -> Self {
+		Self::from_entries([], 9, RunExpectancyMatrix::TYPICAL)
+	}
+}
+
+/// A play and the home team's win probability before and after it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlayWinProbability<'a> {
+	pub play: &'a Play,
+	pub before: GameState,
+	pub home_win_probability_before: f64,
+	pub home_win_probability_after: f64,
+	/// Leverage of the state before the play, see [`WinExpectancy::leverage_index`].
+	pub leverage_index: f64,
+}
+
+impl PlayWinProbability<'_> {
+	/// Win probability added for the home team.
+	#[must_use]
+	pub fn home_wpa(&self) -> f64 {
+		self.home_win_probability_after - self.home_win_probability_before
+	}
+
+	/// Win probability added for the batting team, credited to the batter.
+	#[must_use]
+	pub fn batting_wpa(&self) -> f64 {
+		match self.before.inning_half {
+			InningHalf::Top => -self.home_wpa(),
+			InningHalf::Bottom => self.home_wpa(),
+		}
+	}
+}
+
+/// WPA totals per player.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct PlayerWinProbabilityAdded {
+	pub batters: FxHashMap<PersonId, f64>,
+	/// The negative of the batters' WPA against them.
+	pub pitchers: FxHashMap<PersonId, f64>,
+}
+
+impl WinExpectancy {
+	/// A model from observed outcomes, such as a table stored from an earlier [`Self::from_plays`].
+	#[must_use]
+	pub fn from_entries(entries: impl IntoIterator<Item = WinExpectancyEntry>, scheduled_innings: usize, run_expectancy: RunExpectancyMatrix) -> Self {
+		let mut model = Self { records: FxHashMap::default(), scheduled_innings, run_expectancy, average_swing: 1.0 };
+		for entry in entries {
+			let state = GameState {
+				inning: Inning::from(entry.inning),
+				inning_half: if entry.is_bottom { InningHalf::Bottom } else { InningHalf::Top },
+				outs: entry.outs,
+				bases: BaseOccupancy::from_index(entry.bases),
+				run_differential: entry.run_differential,
+			};
+			let record = model.records.entry(model.bucket(state)).or_default();
+			record.0 += entry.home_wins;
+			record.1 += entry.games;
+		}
+		model.average_swing = model.compute_average_swing();
+		model
+	}
+
+	/// Builds the table from finished games; each plate appearance's starting state is recorded along with whether the home team went on to win.
+	///
+	/// Games whose last play didn't end the game (in progress, suspended, or called early) are skipped. Run expectancy is also computed from the `games`.
+	#[must_use]
+	pub fn from_plays<'a>(games: impl IntoIterator<Item = &'a Plays> + Clone, scheduled_innings: usize) -> Self {
+		let mut entries = FxHashMap::<GameState, (usize, usize)>::default();
+		for plays in games.clone() {
+			let Some(last) = plays.iter().rfind(|play| play.about.is_complete) else { continue };
+			if !ends_game(last, scheduled_innings) {
+				continue;
+			}
+			let home_won = usize::from(run_differential(last) > 0);
+			for (_, state) in game_states(plays) {
+				let record = entries.entry(state).or_default();
+				record.0 += home_won;
+				record.1 += 1;
+			}
+		}
+		let entries = entries.into_iter().map(|(state, (home_wins, games))| entry(state, home_wins, games));
+		Self::from_entries(entries, scheduled_innings, RunExpectancyMatrix::from_plays(games))
+	}
+
+	/// Every observed state, for storing the table.
+	#[must_use]
+	pub fn entries(&self) -> Vec<WinExpectancyEntry> {
+		self.records.iter().map(|(&state, &(home_wins, games))| entry(state, home_wins, games)).collect()
+	}
+
+	/// States are grouped past the scheduled innings (every extra inning is alike) and beyond a ten run lead.
+	fn bucket(&self, state: GameState) -> GameState {
+		GameState {
+			inning: Inning::from((*state.inning).min(self.scheduled_innings + 1)),
+			run_differential: state.run_differential.clamp(-10, 10),
+			..state
+		}
+	}
+
+	/// Probability of the home team winning from `state`.
+	#[must_use]
+	#[allow(clippy::cast_precision_loss, reason = "far fewer than 2^52 games")]
+	pub fn home_win_probability(&self, state: GameState) -> f64 {
+		let analytic = self.analytic_home_win_probability(state);
+		if analytic <= 0.0 || analytic >= 1.0 {
+			return analytic;
+		}
+		let (home_wins, games) = self.records.get(&self.bucket(state)).copied().unwrap_or_default();
+		PRIOR_GAMES.mul_add(analytic, home_wins as f64) / (PRIOR_GAMES + games as f64)
+	}
+
+	/// Normal approximation of the final run differential; the rest of this half-inning from run expectancy, and [`RUNS_PER_HALF_INNING`] for the rest.
+	#[allow(clippy::cast_precision_loss, reason = "small numbers")]
+	fn analytic_home_win_probability(&self, state: GameState) -> f64 {
+		let inning = *state.inning;
+		let is_extra_innings = inning > self.scheduled_innings;
+		if state.inning_half == InningHalf::Bottom && inning >= self.scheduled_innings && state.run_differential > 0 {
+			return 1.0;
+		}
+		let current = self.run_expectancy.get(BaseOutState { bases: state.bases, outs: state.outs });
+		let (home_halves, away_halves) = match state.inning_half {
+			InningHalf::Top if is_extra_innings => (1, 0),
+			InningHalf::Top => (self.scheduled_innings + 1 - inning, self.scheduled_innings - inning),
+			InningHalf::Bottom => (self.scheduled_innings.saturating_sub(inning), self.scheduled_innings.saturating_sub(inning)),
+		};
+		let current_sign: f64 = if state.inning_half == InningHalf::Bottom { 1.0 } else { -1.0 };
+		let mean = RUNS_PER_HALF_INNING.mul_add(home_halves as f64 - away_halves as f64, current_sign.mul_add(current, f64::from(state.run_differential)));
+		let variance = RUN_VARIANCE_PER_HALF_INNING * (current / RUNS_PER_HALF_INNING + (home_halves + away_halves) as f64);
+		if variance <= f64::EPSILON {
+			return match mean.partial_cmp(&0.0) {
+				Some(std::cmp::Ordering::Greater) => 1.0,
+				Some(std::cmp::Ordering::Less) => 0.0,
+				_ => 0.5,
+			};
+		}
+		let standard_deviation = variance.sqrt();
+		// a tie after regulation goes to extra innings, a coin flip.
+		let win = 1.0 - normal_cdf((0.5 - mean) / standard_deviation);
+		let tie = normal_cdf((0.5 - mean) / standard_deviation) - normal_cdf((-0.5 - mean) / standard_deviation);
+		0.5f64.mul_add(tie, win)
+	}
+
+	/// How much the next plate appearance can swing the game compared to an average plate appearance; 1 is average, above 2 is high leverage.
+	#[must_use]
+	pub fn leverage_index(&self, state: GameState) -> f64 {
+		self.swing(state) / self.average_swing
+	}
+
+	/// Expected absolute change in win probability over the next plate appearance.
+	fn swing(&self, state: GameState) -> f64 {
+		let probability = self.home_win_probability(state);
+		OUTCOMES.iter().map(|&(outcome, frequency)| frequency * (self.home_win_probability(outcome.apply(state)) - probability).abs()).sum()
+	}
+
+	/// Mean [`Self::swing`] over typical plate appearances; the observed ones if there are any.
+	#[allow(clippy::cast_precision_loss, reason = "far fewer than 2^52 games")]
+	fn compute_average_swing(&self) -> f64 {
+		let (total, weight) = if self.records.is_empty() {
+			typical_states(self.scheduled_innings).fold((0.0, 0.0), |(total, weight), (state, frequency)| (frequency.mul_add(self.swing(state), total), weight + frequency))
+		} else {
+			self.records.iter().fold((0.0, 0.0), |(total, weight), (&state, &(_, games))| ((games as f64).mul_add(self.swing(state), total), weight + games as f64))
+		};
+		if total > 0.0 { total / weight } else { 1.0 }
+	}
+
+	/// Win probability before and after every complete play.
+	#[must_use]
+	pub fn plays<'a>(&self, plays: &'a Plays) -> Vec<PlayWinProbability<'a>> {
+		let states = game_states(plays);
+		states.iter().enumerate().map(|(idx, &(play, before))| {
+			let home_win_probability_after = match states.get(idx + 1) {
+				Some(&(_, after)) => self.home_win_probability(after),
+				None => self.final_home_win_probability(play),
+			};
+			PlayWinProbability { play, before, home_win_probability_before: self.home_win_probability(before), home_win_probability_after, leverage_index: self.leverage_index(before) }
+		}).collect()
+	}
+
+	/// Win probability after the last play so far; 1 or 0 if it ended the game.
+	fn final_home_win_probability(&self, play: &Play) -> f64 {
+		let run_differential = run_differential(play);
+		if ends_game(play, self.scheduled_innings) {
+			return if run_differential > 0 { 1.0 } else { 0.0 };
+		}
+		let state = GameState { inning: play.about.inning, inning_half: play.about.inning_half, outs: play.count.outs.min(3), bases: play.matchup.ending_bases(), run_differential };
+		if state.outs < 3 { self.home_win_probability(state) } else { self.home_win_probability(state.next_half_inning()) }
+	}
+
+	/// WPA for each batter and pitcher, credited to the batter and pitcher at the end of the plate appearance.
+	#[must_use]
+	pub fn players<'a>(&self, games: impl IntoIterator<Item = &'a Plays>) -> PlayerWinProbabilityAdded {
+		let mut values = PlayerWinProbabilityAdded::default();
+		for plays in games {
+			for play in self.plays(plays) {
+				let matchup = &play.play.matchup;
+				*values.batters.entry(matchup.batter.id).or_default() += play.batting_wpa();
+				*values.pitchers.entry(matchup.pitcher.id).or_default() -= play.batting_wpa();
+			}
+		}
+		values
+	}
+
+	/// The `count` plays with the largest swing in win probability, largest first.
+	#[must_use]
+	pub fn top_plays<'a>(&self, plays: &'a Plays, count: usize) -> Vec<PlayWinProbability<'a>> {
+		let mut plays = self.plays(plays);
+		plays.sort_by(|a, b| b.home_wpa().abs().total_cmp(&a.home_wpa().abs()));
+		plays.truncate(count);
+		plays
+	}
+}
+
+/// Simplified plate appearance results for the leverage index, runners advance the minimum reasonable number of bases.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum PlateAppearanceOutcome {
+	Out,
+	Walk,
+	Single,
+	Double,
+	Triple,
+	HomeRun,
+}
+
+impl PlateAppearanceOutcome {
+	fn apply(self, state: GameState) -> GameState {
+		let BaseOccupancy { first, second, third } = state.bases;
+		let (bases, runs) = match self {
+			Self::Out if state.outs >= 2 => return state.next_half_inning(),
+			Self::Out => return GameState { outs: state.outs + 1, ..state },
+			Self::Walk => (BaseOccupancy { first: true, second: second || first, third: third || (first && second) }, u8::from(first && second && third)),
+			Self::Single => (BaseOccupancy { first: true, second: first, third: false }, u8::from(second) + u8::from(third)),
+			Self::Double => (BaseOccupancy { first: false, second: true, third: first }, u8::from(second) + u8::from(third)),
+			Self::Triple => (BaseOccupancy { first: false, second: false, third: true }, u8::try_from(state.bases.runners()).unwrap_or(3)),
+			Self::HomeRun => (BaseOccupancy::default(), u8::try_from(state.bases.runners()).unwrap_or(3) + 1),
+		};
+		let runs = i32::from(runs) * if state.inning_half == InningHalf::Bottom { 1 } else { -1 };
+		GameState { bases, run_differential: state.run_differential + runs, ..state }
+	}
+}
+
+/// Rough frequencies of base states and run differentials over regulation innings, for the analytic [`WinExpectancy::leverage_index`] baseline.
+fn typical_states(scheduled_innings: usize) -> impl Iterator<Item = (GameState, f64)> {
+	const BASES: [f64; 8] = [0.55, 0.2, 0.07, 0.06, 0.03, 0.04, 0.02, 0.03];
+	const RUN_DIFFERENTIALS: [(i32, f64); 9] = [(0, 0.28), (1, 0.15), (-1, 0.15), (2, 0.1), (-2, 0.1), (3, 0.06), (-3, 0.06), (4, 0.05), (-4, 0.05)];
+	(1..=scheduled_innings).flat_map(move |inning| [InningHalf::Top, InningHalf::Bottom].into_iter().flat_map(move |inning_half| (0..3).flat_map(move |outs| {
+		BASES.iter().enumerate().flat_map(move |(bases, bases_frequency)| RUN_DIFFERENTIALS.iter().map(move |&(run_differential, frequency)| {
+			(GameState { inning: Inning::from(inning), inning_half, outs, bases: BaseOccupancy::from_index(bases), run_differential }, bases_frequency * frequency)
+		}))
+	})))
+}
+
+/// Whether the game is over after `play`; a walk-off, or the last out of a regulation or extra inning with a team ahead.
+fn ends_game(play: &Play, scheduled_innings: usize) -> bool {
+	let run_differential = run_differential(play);
+	let is_last_inning = *play.about.inning >= scheduled_innings && run_differential != 0;
+	match play.about.inning_half {
+		InningHalf::Bottom => is_last_inning && (run_differential > 0 || play.count.outs >= 3),
+		InningHalf::Top => is_last_inning && run_differential > 0 && play.count.outs >= 3,
+	}
+}
+
+/// Home runs minus away runs after the play.
+fn run_differential(play: &Play) -> i32 {
+	let score = HomeAway::new(play.result.home_score, play.result.away_score).map(|runs| i32::try_from(runs).unwrap_or(i32::MAX));
+	score.home - score.away
+}
+
+/// Each complete play and the [`GameState`] at its start.
+fn game_states(plays: &Plays) -> Vec<(&Play, GameState)> {
+	let mut previous: Option<&Play> = None;
+	plays.iter().filter(|play| play.about.is_complete).map(|play| {
+		let (bases, outs) = play.starting_situation(previous);
+		let state = GameState { inning: play.about.inning, inning_half: play.about.inning_half, outs, bases, run_differential: previous.map_or(0, run_differential) };
+		previous = Some(play);
+		(play, state)
+	}).collect()
+}
+
+const fn entry(state: GameState, home_wins: usize, games: usize) -> WinExpectancyEntry {
+	WinExpectancyEntry {
+		inning: state.inning.0,
+		is_bottom: matches!(state.inning_half, InningHalf::Bottom),
+		outs: state.outs,
+		bases: state.bases.index(),
+		run_differential: state.run_differential,
+		home_wins,
+		games,
+	}
+}
+
+/// Standard normal cumulative distribution function (Abramowitz & Stegun 7.1.26, error below 1.5e-7).
+fn normal_cdf(x: f64) -> f64 {
+	let z = x.abs() / std::f64::consts::SQRT_2;
+	let t = 1.0 / 0.327_591_1f64.mul_add(z, 1.0);
+	let polynomial = t * 1.061_405_429f64.mul_add(t, -1.453_152_027).mul_add(t, 1.421_413_741).mul_add(t, -0.284_496_736).mul_add(t, 0.254_829_592);
+	let erf = polynomial.mul_add(-(-z * z).exp(), 1.0);
+	if x >= 0.0 { f64::midpoint(1.0, erf) } else { f64::midpoint(1.0, -erf) }
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{GameState, InningHalf, LiveFeedRequest, PlayWinProbability, WinExpectancy};
+	use crate::request::RequestURLBuilderExt;
+
+	#[test]
+	fn analytic_win_expectancy() {
+		let model = WinExpectancy::default();
+		let start = model.home_win_probability(GameState::starting());
+		assert!((0.45..0.6).contains(&start), "{start}");
+		let up_three_in_the_ninth = GameState { inning: 9.into(), inning_half: InningHalf::Top, outs: 2, run_differential: 3, ..GameState::starting() };
+		assert!(model.home_win_probability(up_three_in_the_ninth) > 0.95);
+		assert!(model.leverage_index(up_three_in_the_ninth) < 1.0);
+		let down_one_in_the_ninth = GameState { inning: 9.into(), inning_half: InningHalf::Bottom, outs: 0, run_differential: -1, ..GameState::starting() };
+		assert!(model.leverage_index(down_one_in_the_ninth) > 2.0);
+	}
+
+	#[tokio::test]
+	async fn ws_gm7_2025_win_probability_added() {
+		let feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		let model = WinExpectancy::default();
+		let plays = model.plays(&feed.live.plays);
+		let last = plays.last().unwrap();
+		let home_won = feed.live.linescore.rhe_totals.home.runs > feed.live.linescore.rhe_totals.away.runs;
+		assert!((last.home_win_probability_after - if home_won { 1.0 } else { 0.0 }).abs() < 1e-9);
+		let total = plays.iter().map(PlayWinProbability::home_wpa).sum::<f64>();
+		assert!((total - (last.home_win_probability_after - plays[0].home_win_probability_before)).abs() < 1e-9);
+		assert_eq!(model.top_plays(&feed.live.plays, 5).len(), 5);
+
+		assert_ne!(WinExpectancy::from_plays([&feed.live.plays], 9).entries().len(), 0);
+		// the game as it stood with the Blue Jays ahead in the 3rd
+		let mut in_progress = feed.live.plays.clone();
+		let lead = in_progress.iter().position(|play| play.result.home_score > play.result.away_score).unwrap();
+		// SAFETY: the in-progress game is only used through `Plays::iter`
+		unsafe { in_progress.plays_mut() }.truncate(lead + 1);
+		assert_eq!(WinExpectancy::from_plays([&in_progress], 9).entries().len(), 0);
+	}
+}
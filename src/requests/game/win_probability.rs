@@ -1 +1,153 @@
+//! Win probability, win probability added (WPA), and leverage index of each play, as computed by MLB.
+//!
+//! For games the endpoint doesn't cover, see [`WinExpectancy`](crate::game::WinExpectancy).
 
+use std::fmt::{Display, Formatter};
+
+use bon::Builder;
+use derive_more::Deref;
+use serde::Deserialize;
+use serde::de::IgnoredAny;
+
+use crate::{HomeAway, game::{GameId, InningHalf, Plays}, request::RequestURL};
+
+/// Win probability data of every play so far, see [`Plays::merge_win_probability`].
+#[derive(Debug, Deserialize, PartialEq, Clone, Deref)]
+#[serde(transparent)]
+pub struct WinProbabilityResponse {
+	pub plays: Vec<WinProbabilityData>,
+}
+
+/// Win probability after a play, corresponding to the [`Play`](crate::game::Play) with the same [`at_bat_idx`](crate::game::PlayAbout::at_bat_idx).
+#[derive(Debug, Deserialize, PartialEq, Copy, Clone)]
+#[serde(from = "__WinProbabilityDataStruct")]
+pub struct WinProbabilityData {
+	pub at_bat_idx: usize,
+	/// Probability of each team winning, from 0 to 1.
+	pub win_probability: HomeAway<f64>,
+	/// Change in the home team's win probability over the play, from -1 to 1.
+	pub home_win_probability_added: f64,
+	/// How much the play could swing the game compared to an average play, 1 is average.
+	pub leverage_index: Option<f64>,
+}
+
+impl WinProbabilityData {
+	/// Change in the batting team's win probability, credited to the batter.
+	#[must_use]
+	pub fn batting_win_probability_added(&self, inning_half: InningHalf) -> f64 {
+		match inning_half {
+			InningHalf::Top => -self.home_win_probability_added,
+			InningHalf::Bottom => self.home_win_probability_added,
+		}
+	}
+}
+
+#[doc(hidden)]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "_debug", serde(deny_unknown_fields))]
+struct __WinProbabilityDataStruct {
+	#[serde(rename = "atBatIndex")]
+	at_bat_idx: usize,
+	home_team_win_probability: f64,
+	away_team_win_probability: f64,
+	#[serde(default)]
+	home_team_win_probability_added: f64,
+	#[serde(default)]
+	leverage_index: Option<f64>,
+
+	// the rest of the play, see `LiveFeedResponse`.
+	#[serde(rename = "result", default)]
+	__result: IgnoredAny,
+	#[serde(rename = "about", default)]
+	__about: IgnoredAny,
+	#[serde(rename = "count", default)]
+	__count: IgnoredAny,
+	#[serde(rename = "matchup", default)]
+	__matchup: IgnoredAny,
+	#[serde(rename = "pitchIndex", default)]
+	__pitch_index: IgnoredAny,
+	#[serde(rename = "actionIndex", default)]
+	__action_index: IgnoredAny,
+	#[serde(rename = "runnerIndex", default)]
+	__runner_index: IgnoredAny,
+	#[serde(rename = "runners", default)]
+	__runners: IgnoredAny,
+	#[serde(rename = "playEvents", default)]
+	__play_events: IgnoredAny,
+	#[serde(rename = "playEndTime", default)]
+	__play_end_time: IgnoredAny,
+	#[serde(rename = "reviewDetails", default)]
+	__review_details: IgnoredAny,
+	#[serde(rename = "contextMetrics", default)]
+	__context_metrics: IgnoredAny,
+}
+
+impl From<__WinProbabilityDataStruct> for WinProbabilityData {
+	fn from(value: __WinProbabilityDataStruct) -> Self {
+		Self {
+			at_bat_idx: value.at_bat_idx,
+			win_probability: HomeAway::new(value.home_team_win_probability / 100.0, value.away_team_win_probability / 100.0),
+			home_win_probability_added: value.home_team_win_probability_added / 100.0,
+			leverage_index: value.leverage_index,
+		}
+	}
+}
+
+impl Plays {
+	/// Attaches the [`WinProbabilityData`] to each [`Play::win_probability`](crate::game::Play::win_probability) by at bat index.
+	///
+	/// Useful before handing a feed to [`PlayStream::run_with_feed`](crate::game::PlayStream::run_with_feed), [`PlayStream::with_win_probability`](crate::game::PlayStream::with_win_probability) does this every poll after.
+	pub fn merge_win_probability(&mut self, response: &WinProbabilityResponse) {
+		for data in &response.plays {
+			// SAFETY: win probability isn't part of the scoring play or inning caches
+			if let Some(play) = unsafe { self.plays_mut() }.get_mut(data.at_bat_idx).filter(|play| play.about.at_bat_idx == data.at_bat_idx) {
+				play.win_probability = Some(*data);
+			}
+			if let Some(play) = self.current_play.as_mut().filter(|play| play.about.at_bat_idx == data.at_bat_idx) {
+				play.win_probability = Some(*data);
+			}
+		}
+	}
+}
+
+/// Returns a [`WinProbabilityResponse`]
+#[derive(Builder)]
+#[builder(derive(Into))]
+pub struct WinProbabilityRequest {
+	#[builder(into)]
+	id: GameId,
+}
+
+impl<S: win_probability_request_builder::State + win_probability_request_builder::IsComplete> crate::request::RequestURLBuilderExt for WinProbabilityRequestBuilder<S> {
+	type Built = WinProbabilityRequest;
+}
+
+impl Display for WinProbabilityRequest {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		write!(f, "http://statsapi.mlb.com/api/v1/game/{}/winProbability", self.id)
+	}
+}
+
+impl RequestURL for WinProbabilityRequest {
+	type Response = WinProbabilityResponse;
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::game::{LiveFeedRequest, WinProbabilityRequest};
+	use crate::request::RequestURLBuilderExt;
+
+	#[tokio::test]
+	async fn ws_gm7_2025_win_probability() {
+		let response = WinProbabilityRequest::builder().id(813_024).build_and_get().await.unwrap();
+		assert_ne!(response.len(), 0);
+		assert!(response.iter().all(|data| (data.win_probability.home + data.win_probability.away - 1.0).abs() < 0.01));
+
+		let mut feed = LiveFeedRequest::builder().id(813_024).build_and_get().await.unwrap();
+		feed.live.plays.merge_win_probability(&response);
+		let last = feed.live.plays.last().unwrap().win_probability.unwrap();
+		let home_won = feed.live.linescore.rhe_totals.home.runs > feed.live.linescore.rhe_totals.away.runs;
+		assert!((last.win_probability.home - if home_won { 1.0 } else { 0.0 }).abs() < 0.01);
+	}
+}
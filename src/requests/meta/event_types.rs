@@ -510,6 +510,56 @@ event_type! {
     // --------- -- ---- ---------
 }
 
+impl EventType {
+    /// Any strikeout, including those that end in double or triple plays.
+    #[must_use]
+    pub const fn is_strikeout(self) -> bool {
+        matches!(self, Self::Strikeout | Self::StrikeoutDoublePlay | Self::StrikeoutTriplePlay)
+    }
+
+    /// Base on balls, intentional or not.
+    #[must_use]
+    pub const fn is_walk(self) -> bool {
+        matches!(self, Self::Walk | Self::IntentionalWalk)
+    }
+
+    /// Sacrifice bunts and flies, including those that end in double plays.
+    #[must_use]
+    pub const fn is_sacrifice(self) -> bool {
+        matches!(self, Self::SacrificeBunt | Self::SacrificeBuntDoublePlay | Self::SacrificeFly | Self::SacrificeFlyDoublePlay)
+    }
+
+    /// Whether the plate appearance counts as an official at-bat.
+    #[must_use]
+    pub const fn is_at_bat(self) -> bool {
+        self.is_plate_appearance() && !self.is_walk() && !self.is_sacrifice() && !matches!(self, Self::HitByPitch | Self::CatchersInterference)
+    }
+
+    /// Successful stolen base, any base.
+    #[must_use]
+    pub const fn is_stolen_base(self) -> bool {
+        matches!(self, Self::StolenBase | Self::StolenBase2B | Self::StolenBase3B | Self::StolenBaseHome)
+    }
+
+    /// Caught stealing, including runners picked off while going early.
+    #[must_use]
+    pub const fn is_caught_stealing(self) -> bool {
+        matches!(self, Self::CaughtStealing | Self::CaughtStealing2B | Self::CaughtStealing3B | Self::CaughtStealingHome | Self::CaughtStealingDoublePlay | Self::PickoffCaughtStealing2B | Self::PickoffCaughtStealing3B | Self::PickoffCaughtStealingHome)
+    }
+
+    /// Number of bases the batter is credited with; `0` for anything other than a hit.
+    #[must_use]
+    pub const fn total_bases(self) -> usize {
+        match self {
+            Self::Single => 1,
+            Self::Double => 2,
+            Self::Triple => 3,
+            Self::HomeRun => 4,
+            _ => 0,
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for EventType {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
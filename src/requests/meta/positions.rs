@@ -1,9 +1,57 @@
 use std::hash::{Hash, Hasher};
-use derive_more::{Deref, DerefMut};
+use derive_more::{Deref, DerefMut, Display};
 use serde::Deserialize;
 
 id!(#[doc = "A [`String`] representing a position on the field, such as Pitcher, 1st Baseman, etc. These values use 1-9, so Pitcher = \"1\", etc."] PositionCode { code: String });
 
+/// One of the nine positions on the field, numbered as in scorekeeping (Pitcher = 1, ..., Right Field = 9).
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Display, Hash)]
+pub enum FieldingPosition {
+	#[display("Pitcher")]
+	Pitcher = 1,
+
+	#[display("Catcher")]
+	Catcher,
+
+	#[display("First Base")]
+	FirstBase,
+
+	#[display("Second Base")]
+	SecondBase,
+
+	#[display("Third Base")]
+	ThirdBase,
+
+	#[display("Shortstop")]
+	Shortstop,
+
+	#[display("Left Field")]
+	LeftField,
+
+	#[display("Center Field")]
+	CenterField,
+
+	#[display("Right Field")]
+	RightField,
+}
+
+impl FieldingPosition {
+	/// Every position, pitcher through right field.
+	pub const ALL: [Self; 9] = [Self::Pitcher, Self::Catcher, Self::FirstBase, Self::SecondBase, Self::ThirdBase, Self::Shortstop, Self::LeftField, Self::CenterField, Self::RightField];
+
+	/// The position with this [`PositionCode`], if it is one of the nine on the field (not a DH, pinch hitter, etc.).
+	#[must_use]
+	pub fn from_code(code: &PositionCode) -> Option<Self> {
+		let number = code.parse::<usize>().ok()?;
+		Self::ALL.get(number.checked_sub(1)?).copied()
+	}
+
+	#[must_use]
+	pub fn code(self) -> PositionCode {
+		PositionCode::new((self as u8).to_string())
+	}
+}
+
 /// A [`Position`] with a name.
 ///
 /// ## Examples
@@ -34,6 +82,12 @@ impl Hash for NamedPosition {
 }
 
 impl NamedPosition {
+	/// The position on the field, [`None`] for a DH, pinch hitter, etc.
+	#[must_use]
+	pub fn fielding_position(&self) -> Option<FieldingPosition> {
+		FieldingPosition::from_code(&self.code)
+	}
+
 	#[must_use]
 	pub fn unknown_position() -> Self {
 		Self {
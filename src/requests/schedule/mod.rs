@@ -78,6 +78,8 @@ pub struct ScheduleGame<H: ScheduleHydrations> {
 	pub inning_break_length: uom::si::i32::Time,
 	/// [`None`] if the current game is not of a series-format (ex: [Spring Training](`GameType::SpringTraining`))
 	pub series_data: Option<SeriesData>,
+	/// See [`ScheduleHydrations::WeatherForecast`].
+	pub weather_forecast: H::WeatherForecast,
 }
 
 #[serde_as]
@@ -116,6 +118,8 @@ struct __ScheduleGameStruct<H: ScheduleHydrations> {
 	inning_break_length: Option<u32>,
 	#[serde(flatten)]
 	series_data: Option<SeriesData>,
+	#[serde(rename = "weather", alias = "weatherForecast", default)]
+	weather_forecast: H::WeatherForecast,
 }
 
 impl<H: ScheduleHydrations> From<__ScheduleGameStruct<H>> for ScheduleGame<H> {
@@ -143,6 +147,7 @@ impl<H: ScheduleHydrations> From<__ScheduleGameStruct<H>> for ScheduleGame<H> {
 			reverse_home_away_status,
 			inning_break_length,
 			series_data,
+			weather_forecast,
 		}: __ScheduleGameStruct<H>,
 	) -> Self {
 		Self {
@@ -167,6 +172,7 @@ impl<H: ScheduleHydrations> From<__ScheduleGameStruct<H>> for ScheduleGame<H> {
 			reverse_home_away_status,
 			inning_break_length: uom::si::i32::Time::new::<uom::si::time::second>(inning_break_length.unwrap_or(120) as i32),
 			series_data,
+			weather_forecast,
 		}
 	}
 }
@@ -228,12 +234,17 @@ pub trait ScheduleHydrations: Hydrations<RequestData = ()> {
 	type Team: Debug + DeserializeOwned + Clone + PartialEq;
 
 	type Venue: Debug + DeserializeOwned + Clone + PartialEq;
+
+	/// [`WeatherConditions`] expected at first pitch, `()` if not hydrated.
+	type WeatherForecast: Debug + DeserializeOwned + Clone + PartialEq + Default;
 }
 
 impl ScheduleHydrations for () {
 	type Team = NamedTeam;
 
 	type Venue = NamedVenue;
+
+	type WeatherForecast = ();
 }
 
 /// Creates hydrations for a schedule
@@ -291,10 +302,11 @@ impl ScheduleHydrations for () {
 /// | `abs_challenge`                                |                      |
 /// | `acs_challenge`                                |                      |
 /// | `status_flags`                                 |                      |
-/// | `weather_forecast`                             |                      |
+/// | `weather_forecast`                             | [`WeatherConditions`]|
 ///
 /// [`team_hydrations!`]: crate::team_hydrations
 /// [`venue_hydrations!`]: crate::venue_hydrations
+/// [`WeatherConditions`]: crate::game::WeatherConditions
 #[macro_export]
 macro_rules! schedule_hydrations {
 	(@ inline_structs [team: { $($inline_tt:tt)* } $(, $($tt:tt)*)?] $vis:vis struct $name:ident { $($field_tt:tt)* }) => {
@@ -354,9 +366,13 @@ macro_rules! schedule_hydrations {
     (@ venue) => { $crate::venue::NamedVenue };
 	(@ venue $hydrations:ty) => { $crate::venue::Venue<$hydrations> };
 
+    (@ weather_forecast) => { () };
+	(@ weather_forecast $comma:tt) => { ::core::option::Option<$crate::game::WeatherConditions> };
+
 	(@ actual $vis:vis struct $name:ident {
 		$(team: $team:ty ,)?
-		$(venue: $venue:ty)?
+		$(venue: $venue:ty ,)?
+		$(weather_forecast $weather_forecast_comma:tt)?
 	}) => {
 		#[derive(::core::fmt::Debug, $crate::macro_use::serde::Deserialize, ::core::cmp::PartialEq, ::core::clone::Clone)]
 		#[serde(rename_all = "camelCase")]
@@ -368,14 +384,16 @@ macro_rules! schedule_hydrations {
 			type Team = $crate::schedule_hydrations!(@ team $($team)?);
 
 			type Venue = $crate::schedule_hydrations!(@ venue $($venue)?);
+
+			type WeatherForecast = $crate::schedule_hydrations!(@ weather_forecast $($weather_forecast_comma)?);
 		}
 
 		impl $crate::hydrations::Hydrations for $name {
 			type RequestData = ();
 
-			fn hydration_text(&(): Self::RequestData) -> ::std::borrow::Cow<'static, str> {
+			fn hydration_text(&(): &Self::RequestData) -> ::std::borrow::Cow<'static, str> {
 				let text = ::std::borrow::Cow::Borrowed(::core::concat!(
-
+					$("weatherForecast," $weather_forecast_comma)?
 				));
 
 				$(let text = ::std::borrow::Cow::Owned(::std::format!("{text}team({}),", <$team as $crate::hydrations::Hydrations>::hydration_text(&()))))?
@@ -385,6 +403,9 @@ macro_rules! schedule_hydrations {
 			}
 		}
 	};
+	($vis:vis struct $name:ident { $($tt:tt)* }) => {
+		$crate::schedule_hydrations! { @ inline_structs [$($tt)*] $vis struct $name {} }
+	};
 }
 
 #[allow(dead_code, reason = "rust analyzer says that opponent_id and season are dead, while being used in Display")]
@@ -499,6 +520,21 @@ mod tests {
 			.unwrap();
 	}
 
+	#[tokio::test]
+	async fn test_weather_forecast() {
+		crate::schedule_hydrations! {
+			struct WeatherForecastHydrations {
+				weather_forecast
+			}
+		}
+
+		// 2025 World Series game 7
+		let date = NaiveDate::from_ymd_opt(2025, 11, 1).unwrap();
+		let schedule = ScheduleRequest::<WeatherForecastHydrations>::builder().date(date).sport_id(crate::sport::SportId::MLB).build_and_get().await.unwrap();
+		let mut games = schedule.dates.iter().flat_map(|date| &date.games).filter(|game| !game.venue.is_unknown());
+		assert!(games.any(|game| game.weather_forecast.is_some()), "Expected a game with a weather forecast");
+	}
+
 	#[tokio::test]
 	#[cfg_attr(not(feature = "_heavy_tests"), ignore)]
 	async fn test_all_dates_all_years() {